@@ -13,8 +13,12 @@ pub mod tower_sprites;
 pub mod ui_assets;
 
 pub use {
-    game_assets::GameAssets, level_assets::LevelAssets, liquid_sprites::LiquidSprites,
-    sound_effects::SoundEffects, status_sprites::StatusSprites, tower_sprites::TowerSprites,
+    game_assets::GameAssets,
+    level_assets::LevelAssets,
+    liquid_sprites::LiquidSprites,
+    sound_effects::SoundEffects,
+    status_sprites::{STATUS_ICON_SIZE, StatusSheet, StatusSprites},
+    tower_sprites::TowerSprites,
     ui_assets::UiAssets,
 };
 
@@ -1,9 +1,11 @@
 use crate::data::status_effects::StatusEnum;
 use crate::gameplay::animation::AnimationFrameQueue;
-use crate::level::components::LEVEL_SCALING;
 use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
 
+/// Size of each status icon in the row above an enemy's health bar
+pub const STATUS_ICON_SIZE: f32 = 2.5;
+
 #[derive(AssetCollection, Resource)]
 pub struct StatusSprites {
     #[asset(path = "images/statuseffects/wet.png")]
@@ -47,44 +49,45 @@ pub struct StatusSprites {
     slowed_layout: Handle<TextureAtlasLayout>,
 }
 
+/// The sheets status effects can be drawn with. Statuses pick theirs through
+/// `StatusEffectTrait::sprite_sheet`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum StatusSheet {
+    Wet,
+    Acidic,
+    Burning,
+    Electrified,
+    Frozen,
+    Oiled,
+    Pushed,
+    Slowed,
+}
+
 impl StatusSprites {
     pub fn status_sprite(
         &self,
-        status_effect: StatusEnum,
+        sheet: StatusSheet,
     ) -> (&Handle<Image>, &Handle<TextureAtlasLayout>) {
-        match status_effect {
-            StatusEnum::Wet => (&self.wet_sprite, &self.wet_layout),
-            StatusEnum::Burned => (&self.burning_sprite, &self.burning_layout),
-            StatusEnum::Frozen => (&self.frozen_sprite, &self.frozen_layout),
-            StatusEnum::Electrocuted => (&self.electrified_sprite, &self.electrified_layout),
-            StatusEnum::Acidified => (&self.acidic_sprite, &self.acidic_layout),
-            StatusEnum::Oiled => (&self.oiled_sprite, &self.oiled_layout),
-            StatusEnum::Ignited => (&self.burning_sprite, &self.burning_layout),
-            StatusEnum::Chilled => (&self.frozen_sprite, &self.frozen_layout),
-        }
-    }
-
-    pub fn status_animation_frames(&self, status_effect: StatusEnum) -> &'static [usize] {
-        match status_effect {
-            StatusEnum::Wet => &[0, 1, 2, 3, 4, 5],
-            StatusEnum::Burned => &[0, 1, 2, 3, 4, 5],
-            StatusEnum::Frozen => &[0, 2, 4],
-            StatusEnum::Electrocuted => &[0, 2, 4],
-            StatusEnum::Acidified => &[0, 1, 2, 3, 4, 5, 6, 6, 6, 6],
-            StatusEnum::Oiled => &[0, 1, 2, 3, 4, 5],
-            StatusEnum::Ignited => &[0, 1, 2, 3, 4, 5],
-            StatusEnum::Chilled => &[0, 1, 2, 3, 4, 5],
+        match sheet {
+            StatusSheet::Wet => (&self.wet_sprite, &self.wet_layout),
+            StatusSheet::Acidic => (&self.acidic_sprite, &self.acidic_layout),
+            StatusSheet::Burning => (&self.burning_sprite, &self.burning_layout),
+            StatusSheet::Electrified => (&self.electrified_sprite, &self.electrified_layout),
+            StatusSheet::Frozen => (&self.frozen_sprite, &self.frozen_layout),
+            StatusSheet::Oiled => (&self.oiled_sprite, &self.oiled_layout),
+            StatusSheet::Pushed => (&self.pushed_sprite, &self.pushed_layout),
+            StatusSheet::Slowed => (&self.slowed_sprite, &self.slowed_layout),
         }
     }
 
     pub fn status_bundle(&self, status_effect: StatusEnum) -> impl Bundle {
-        let (image, atlas) = self.status_sprite(status_effect);
-        let frames = self.status_animation_frames(status_effect);
+        let (image, atlas) = self.status_sprite(status_effect.sprite_sheet());
+        let frames = status_effect.animation_frames();
 
         (
             Sprite {
                 image: image.clone(),
-                custom_size: Some(Vec2::splat(LEVEL_SCALING / 2.0)),
+                custom_size: Some(Vec2::splat(STATUS_ICON_SIZE)),
                 texture_atlas: Some(TextureAtlas::from(atlas.clone())),
                 ..default()
             },
@@ -66,17 +66,17 @@ pub struct TowerSprites {
 impl TowerSprites {
     pub fn tower_sprite(&self, tower: &Tower) -> (&Handle<Image>, &Handle<TextureAtlasLayout>) {
         match tower {
-            Tower::Piston => (&self.piston_sprite, &self.piston_layout),
-            Tower::Fan => (&self.fan_sprite, &self.fan_layout),
+            Tower::Piston | Tower::Mortar => (&self.piston_sprite, &self.piston_layout),
+            Tower::Fan | Tower::Overclocker => (&self.fan_sprite, &self.fan_layout),
             Tower::SpikePit => (&self.spike_sprite, &self.spike_layout),
             Tower::Oil => (&self.oil_sprite, &self.oil_layout),
             Tower::TrapDoor => (&self.trap_door_sprite, &self.trap_door_layout),
-            Tower::Tesla => (&self.tesla_sprite, &self.tesla_layout),
+            Tower::Tesla | Tower::Generator => (&self.tesla_sprite, &self.tesla_layout),
             Tower::Water => (&self.water_sprite, &self.water_layout),
             Tower::Acid => (&self.acid_sprite, &self.acid_layout),
             Tower::Flame => (&self.flame_sprite, &self.flame_layout),
             Tower::Portal => (&self.portal_sprite, &self.portal_layout),
-            Tower::Ice => (&self.ice_sprite, &self.ice_layout),
+            Tower::Ice | Tower::Amplifier => (&self.ice_sprite, &self.ice_layout),
         }
     }
 
@@ -89,6 +89,7 @@ impl TowerSprites {
         if [
             Tower::Tesla,
             Tower::Piston,
+            Tower::Mortar,
             Tower::Water,
             Tower::Flame,
             Tower::Acid,
@@ -104,6 +105,7 @@ impl TowerSprites {
         (
             Sprite {
                 image: image.clone(),
+                color: tower.sprite_tint().unwrap_or(Color::WHITE),
                 custom_size: Some(Vec2::splat(LEVEL_SCALING)),
                 texture_atlas: Some(TextureAtlas {
                     index: idle_frames[0],
@@ -119,13 +121,13 @@ impl TowerSprites {
 impl CellDirection {
     pub fn idle_frames(&self, tower: &Tower) -> &'static [usize] {
         match tower {
-            Tower::Piston => match self {
+            Tower::Piston | Tower::Mortar => match self {
                 CellDirection::Down => &[4],
                 CellDirection::Up => &[9],
                 CellDirection::Left => &[14],
                 CellDirection::Right => &[14],
             },
-            Tower::Fan => match self {
+            Tower::Fan | Tower::Overclocker => match self {
                 CellDirection::Down => &[0],
                 CellDirection::Up => &[4],
                 CellDirection::Left => &[8],
@@ -149,7 +151,7 @@ impl CellDirection {
                 CellDirection::Left => &[5],
                 CellDirection::Right => &[5],
             },
-            Tower::Tesla => match self {
+            Tower::Tesla | Tower::Generator => match self {
                 CellDirection::Down => &[0, 1, 2, 3, 4],
                 CellDirection::Up => &[10, 11, 12, 13, 14],
                 CellDirection::Left => &[20, 21, 22, 23, 24],
@@ -174,7 +176,7 @@ impl CellDirection {
                 CellDirection::Right => &[33],
             },
             Tower::Portal => &[0],
-            Tower::Ice => match self {
+            Tower::Ice | Tower::Amplifier => match self {
                 CellDirection::Down => &[0],
                 CellDirection::Up => &[7],
                 CellDirection::Left => &[14],
@@ -239,7 +241,7 @@ impl CellDirection {
                 CellDirection::Left => &[2],
                 CellDirection::Right => &[2],
             },
-            Tower::Piston => match self {
+            Tower::Piston | Tower::Mortar => match self {
                 CellDirection::Down => &[0, 1, 2, 3],
                 CellDirection::Up => &[5, 6, 7, 8],
                 CellDirection::Left => &[10, 11, 12, 13],
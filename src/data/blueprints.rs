@@ -0,0 +1,199 @@
+use std::fmt::Display;
+
+use crate::level::components::{Adjacent, ExactPosition, GeneralPosition, WallDirection};
+
+use super::Tower;
+
+/// A saved tower layout for one level. Written out as plain text, one tower per line:
+///
+/// ```text
+/// level 2
+/// Piston 3 4 floor 1
+/// Fan 0 2 left 1
+/// ```
+///
+/// where levels are numbered from 1 like everywhere the player sees them, and the numbers after
+/// a tower are its face's `AdjacentId` unit position and its tier.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Blueprint {
+    /// Index of the level, so one less than the number written out
+    pub level: usize,
+    pub towers: Vec<BlueprintTower>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BlueprintTower {
+    pub tower: Tower,
+    pub face: Adjacent,
+    /// Towers don't have upgrades yet, so this is always 1 for now
+    pub tier: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlueprintError {
+    MissingLevel,
+    WrongLevel { expected: usize, found: usize },
+    BadLine(usize),
+}
+
+impl Display for BlueprintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlueprintError::MissingLevel => write!(f, "Blueprint doesn't say which level it's for"),
+            BlueprintError::WrongLevel { expected, found } => write!(
+                f,
+                "Blueprint is for level {}, not level {}",
+                found + 1,
+                expected + 1
+            ),
+            BlueprintError::BadLine(line) => write!(f, "Couldn't read line {line} of blueprint"),
+        }
+    }
+}
+
+impl Display for Blueprint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "level {}", self.level + 1)?;
+        for entry in self.towers.iter() {
+            writeln!(
+                f,
+                "{:?} {} {} {} {}",
+                entry.tower,
+                entry.face.id.unit_x,
+                entry.face.id.unit_y,
+                position_name(entry.face.exact_position),
+                entry.tier
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Blueprint {
+    /// Reads a blueprint, refusing ones saved for a different level
+    pub fn parse(text: &str, level: usize) -> Result<Self, BlueprintError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+
+        let found = lines
+            .next()
+            .and_then(|(_, line)| line.strip_prefix("level "))
+            .and_then(|n| n.trim().parse::<usize>().ok())
+            .and_then(|n| n.checked_sub(1))
+            .ok_or(BlueprintError::MissingLevel)?;
+        if found != level {
+            return Err(BlueprintError::WrongLevel {
+                expected: level,
+                found,
+            });
+        }
+
+        let towers = lines
+            .map(|(n, line)| parse_tower(line).ok_or(BlueprintError::BadLine(n)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { level, towers })
+    }
+}
+
+fn parse_tower(line: &str) -> Option<BlueprintTower> {
+    let mut words = line.split_whitespace();
+    let name = words.next()?;
+    let tower = Tower::all()
+        .into_iter()
+        .find(|tower| format!("{tower:?}") == name)?;
+    let unit_x = words.next()?.parse().ok()?;
+    let unit_y = words.next()?.parse().ok()?;
+    let (general, exact) = parse_position(words.next()?)?;
+    let tier = words.next().map_or(Some(1), |tier| tier.parse().ok())?;
+    Some(BlueprintTower {
+        tower,
+        face: Adjacent::new(unit_x, unit_y, general, exact),
+        tier,
+    })
+}
+
+fn position_name(position: ExactPosition) -> &'static str {
+    match position {
+        ExactPosition::Floor => "floor",
+        ExactPosition::Ceiling => "ceiling",
+        ExactPosition::Wall(WallDirection::Left) => "left",
+        ExactPosition::Wall(WallDirection::Right) => "right",
+    }
+}
+
+fn parse_position(name: &str) -> Option<(GeneralPosition, ExactPosition)> {
+    match name {
+        "floor" => Some((GeneralPosition::UpDown, ExactPosition::Floor)),
+        "ceiling" => Some((GeneralPosition::UpDown, ExactPosition::Ceiling)),
+        "left" => Some((
+            GeneralPosition::LeftRight,
+            ExactPosition::Wall(WallDirection::Left),
+        )),
+        "right" => Some((
+            GeneralPosition::LeftRight,
+            ExactPosition::Wall(WallDirection::Right),
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blueprint() -> Blueprint {
+        Blueprint {
+            level: 1,
+            towers: vec![
+                BlueprintTower {
+                    tower: Tower::Piston,
+                    face: Adjacent::new(3, 4, GeneralPosition::UpDown, ExactPosition::Floor),
+                    tier: 1,
+                },
+                BlueprintTower {
+                    tower: Tower::Fan,
+                    face: Adjacent::new(
+                        0,
+                        2,
+                        GeneralPosition::LeftRight,
+                        ExactPosition::Wall(WallDirection::Left),
+                    ),
+                    tier: 1,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn round_trips_through_text() {
+        let text = blueprint().to_string();
+        assert!(text.starts_with("level 2\n"));
+        assert_eq!(Blueprint::parse(&text, 1), Ok(blueprint()));
+    }
+
+    #[test]
+    fn rejects_other_levels() {
+        let text = blueprint().to_string();
+        assert_eq!(
+            Blueprint::parse(&text, 0),
+            Err(BlueprintError::WrongLevel {
+                expected: 0,
+                found: 1
+            })
+        );
+        assert_eq!(
+            Blueprint::parse("Piston 3 4 floor 1", 1),
+            Err(BlueprintError::MissingLevel)
+        );
+    }
+
+    #[test]
+    fn reports_the_line_that_failed() {
+        // Blank lines still count towards the line number
+        let text = "level 2\nPiston 3 4 floor 1\n\nPiston 3 up floor 1\n";
+        assert_eq!(Blueprint::parse(text, 1), Err(BlueprintError::BadLine(4)));
+    }
+}
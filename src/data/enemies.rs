@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+/// The kinds of enemy waves are made of. Spawned enemies keep this as a component, and
+/// `prefabs::enemies::enemy` builds each one.
+#[derive(Component, Copy, Clone, Eq, PartialEq, Hash, Debug, Reflect)]
+pub enum EnemyArchetype {
+    Basic,
+    Chonkus,
+    Turbo,
+    Ember,
+    Warlord,
+}
+
+impl EnemyArchetype {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EnemyArchetype::Basic => "Minor Trooper",
+            EnemyArchetype::Chonkus => "Major Trooper",
+            EnemyArchetype::Turbo => "Turbo Trooper",
+            EnemyArchetype::Ember => "Ember Trooper",
+            EnemyArchetype::Warlord => "Trooper Warlord",
+        }
+    }
+
+    /// Health before shields, given to the prefab as its `EnemyHealth`
+    pub fn max_health(&self) -> isize {
+        match self {
+            EnemyArchetype::Warlord => 1500,
+            _ => 100,
+        }
+    }
+
+    /// Resistances and quirks worth preparing for, in a few words each
+    pub fn traits(&self) -> &'static [&'static str] {
+        match self {
+            EnemyArchetype::Basic => &[],
+            EnemyArchetype::Chonkus => &["Armored", "Resists chill and freeze"],
+            EnemyArchetype::Turbo => &["Fast", "Fragile"],
+            EnemyArchetype::Ember => &[
+                "Immune to burns",
+                "Resists burning damage",
+                "Weak to cold",
+                "Explodes on death",
+            ],
+            EnemyArchetype::Warlord => &[
+                "Boss",
+                "Heavily armored",
+                "Regenerating shield",
+                "Shrugs off statuses as it weakens",
+            ],
+        }
+    }
+
+    /// Frame of the troopers sheet shown for this enemy in the UI
+    pub fn icon_frame(&self) -> usize {
+        match self {
+            EnemyArchetype::Basic | EnemyArchetype::Ember => 8,
+            EnemyArchetype::Chonkus | EnemyArchetype::Warlord => 16,
+            EnemyArchetype::Turbo => 0,
+        }
+    }
+
+    pub fn tint(&self) -> Color {
+        match self {
+            EnemyArchetype::Ember => Color::srgb(1.0, 0.55, 0.3),
+            _ => Color::WHITE,
+        }
+    }
+}
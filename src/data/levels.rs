@@ -1,5 +1,5 @@
+use crate::data::{Tower, enemies::EnemyArchetype};
 use crate::gameplay::wave_manager::Wave;
-use crate::prefabs::enemies::{basic_trooper, chonkus_trooper, turbo_trooper};
 use bevy::prelude::*;
 use std::collections::VecDeque;
 
@@ -52,6 +52,25 @@ x^v>>^v
 pub struct LevelData {
     pub maps: Vec<&'static str>,
     pub enemies: Vec<VecDeque<Wave>>,
+    /// Towers first made available on each level, unlocked when the level before it is beaten
+    pub new_towers: Vec<Vec<Tower>>,
+    /// How many of some towers each level allows, on top of limits the towers set themselves.
+    /// Levels past the end of the list don't add any.
+    pub tower_limits: Vec<Vec<(Tower, usize)>>,
+}
+
+impl LevelData {
+    pub fn new_towers(&self, level: usize) -> &[Tower] {
+        self.new_towers
+            .get(level)
+            .map_or(&[], |towers| towers.as_slice())
+    }
+
+    pub fn tower_limits(&self, level: usize) -> &[(Tower, usize)] {
+        self.tower_limits
+            .get(level)
+            .map_or(&[], |limits| limits.as_slice())
+    }
 }
 
 impl Default for LevelData {
@@ -61,6 +80,15 @@ impl Default for LevelData {
                 MAP_TEXT1, MAP_TEXT2, MAP_TEXT3, MAP_TEXT4, MAP_TEXT5, MAP_TEXT6, MAP_TEXT7,
             ],
             enemies: vec![map1(), map2(), map3(), map4(), map5(), map6(), map7()],
+            new_towers: vec![
+                vec![Tower::Piston, Tower::Fan, Tower::SpikePit, Tower::Water],
+                vec![Tower::Oil, Tower::Flame],
+                vec![Tower::Tesla, Tower::TrapDoor],
+                vec![Tower::Ice, Tower::Acid],
+                vec![Tower::Mortar, Tower::Overclocker],
+                vec![Tower::Amplifier, Tower::Generator],
+            ],
+            tower_limits: vec![],
         }
     }
 }
@@ -70,53 +98,57 @@ pub fn map1() -> VecDeque<Wave> {
         //
         // Wave 1
         vec![
-            (vec![basic_trooper()], 2.),
-            (vec![basic_trooper(), turbo_trooper()], 0.),
+            (vec![EnemyArchetype::Basic], 2.),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.),
         ]
         .into(),
         // Wave 2
         vec![
-            (vec![basic_trooper(), turbo_trooper()], 2.),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 2.),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
         ]
         .into(),
         // Wave 3
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.0),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![basic_trooper()], 0.5),
-            (vec![basic_trooper()], 2.),
-            (vec![basic_trooper(), turbo_trooper()], 0.),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.0),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic], 2.),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.),
         ]
         .into(),
         // Wave 4
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.0),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 2.),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.0),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.0),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 2.),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.0),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
         ]
         .into(),
     ]
@@ -128,66 +160,70 @@ pub fn map2() -> VecDeque<Wave> {
         //
         // Wave 1
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper()], 2.),
-            (vec![basic_trooper(), turbo_trooper()], 0.),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic], 2.),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.),
         ]
         .into(),
         //
         // Wave 2
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.),
         ]
         .into(),
         // Wave 3
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.0),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.0),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
         ]
         .into(),
         // Wave 4
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
         ]
         .into(),
     ]
@@ -199,77 +235,93 @@ pub fn map3() -> VecDeque<Wave> {
         //
         // Wave 1
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
         ]
         .into(),
         // Wave 2
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.0),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.0),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
         ]
         .into(),
         // Wave 3
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![basic_trooper(), turbo_trooper()], 1.0),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.0),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 4
         vec![
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 1.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 1.0),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 1.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 1.0),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
     ]
@@ -281,81 +333,89 @@ pub fn map4() -> VecDeque<Wave> {
         //
         // Wave 1
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.),
         ]
         .into(),
         // Wave 2
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.),
         ]
         .into(),
         // Wave 3
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
         ]
         .into(),
         // Wave 4
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 5
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 6
         vec![
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
     ]
@@ -367,119 +427,136 @@ pub fn map5() -> VecDeque<Wave> {
         //
         // Wave 1
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.),
         ]
         .into(),
         // Wave 2
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
         ]
         .into(),
         // Wave 3
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 4
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 5
         vec![
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 6
         vec![
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.75),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.75),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 7
         vec![
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Warlord], 3.0),
         ]
         .into(),
     ]
@@ -491,141 +568,162 @@ pub fn map6() -> VecDeque<Wave> {
         //
         // Wave 1
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![chonkus_trooper(), basic_trooper()], 1.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 1.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.),
         ]
         .into(),
         // Wave 2
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 1.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 1.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 1.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 1.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
         ]
         .into(),
         // Wave 3
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 1.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 1.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 4
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 1.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 1.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 1.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 1.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 5
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.75),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.75),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![chonkus_trooper()], 1.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 1.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 5
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 1.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 1.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 6
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![chonkus_trooper(), basic_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![chonkus_trooper()], 1.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 1.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Warlord], 3.0),
         ]
         .into(),
     ]
@@ -637,178 +735,223 @@ pub fn map7() -> VecDeque<Wave> {
         //
         // Wave 1
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
         ]
         .into(),
         // Wave 2
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 1.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 1.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
         ]
         .into(),
         // Wave 3
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 1.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 1.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 4
         vec![
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 1.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 1.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 5
         vec![
-            (vec![turbo_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![chonkus_trooper(), basic_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![chonkus_trooper()], 1.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 1.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 6
         vec![
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.0),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![chonkus_trooper(), basic_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.0),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![chonkus_trooper()], 1.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 1.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
         ]
         .into(),
         // Wave 6
         vec![
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 0.5,
             ),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.0),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![chonkus_trooper(), basic_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.0),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
             (
-                vec![chonkus_trooper(), basic_trooper(), turbo_trooper()],
+                vec![
+                    EnemyArchetype::Chonkus,
+                    EnemyArchetype::Basic,
+                    EnemyArchetype::Turbo,
+                ],
                 1.5,
             ),
-            (vec![chonkus_trooper()], 1.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.5),
-            (vec![turbo_trooper()], 2.5),
-            (vec![basic_trooper(), turbo_trooper()], 1.0),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![basic_trooper()], 0.5),
-            (vec![turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.75),
-            (vec![turbo_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus], 1.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+            (vec![EnemyArchetype::Turbo], 2.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.0),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
+            (vec![EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Warlord], 3.0),
         ]
         .into(),
     ]
@@ -817,19 +960,19 @@ pub fn map7() -> VecDeque<Wave> {
 
 /*
 
-(vec![chonkus_trooper(), basic_trooper(), turbo_trooper()], 0.5),
+(vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
 
-(vec![chonkus_trooper(), basic_trooper()], 0.5),
+(vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
 
-(vec![chonkus_trooper()], 0.5),
-(vec![ basic_trooper(), turbo_trooper()], 1.5),
-(vec![ turbo_trooper()], 2.5),
+(vec![EnemyArchetype::Chonkus], 0.5),
+(vec![ EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.5),
+(vec![ EnemyArchetype::Turbo], 2.5),
 
 
-(vec![ basic_trooper(), turbo_trooper()], 1.0),
-(vec![ basic_trooper(), turbo_trooper()], 0.75),
+(vec![ EnemyArchetype::Basic, EnemyArchetype::Turbo], 1.0),
+(vec![ EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.75),
 
-(vec![ basic_trooper()], 0.5),
+(vec![ EnemyArchetype::Basic], 0.5),
 
-(vec![ turbo_trooper()], 0.5),
+(vec![ EnemyArchetype::Turbo], 0.5),
 */
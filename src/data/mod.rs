@@ -1,11 +1,18 @@
 use crate::prelude::*;
 use bevy::prelude::*;
-use projectiles::{AttackSpecification, DamageType, Droplet, LiquidType, Puddle};
+use projectiles::{
+    AttackSpecification, DamageType, Droplet, LiquidType, Projectile, ProjectileSpec, Puddle,
+};
 pub use status_effects::{StatusEffect, StatusEffectTrait};
 
+pub mod blueprints;
+pub mod enemies;
 mod input_state;
 pub mod levels;
+pub mod placement;
 pub mod projectiles;
+pub mod reactions;
+pub mod selling;
 mod state;
 pub mod stats;
 pub mod status_effects;
@@ -15,12 +22,14 @@ pub use {
     input_state::PointerInteractionState,
     state::PlayerState,
     towers::get_collision,
-    towers::{Tower, TowerCollision},
+    towers::{Aura, AuraEffect, CELL_AREA, Tower, TowerArea, TowerCollision, TowerElement},
 };
 
 pub(super) fn plugin(app: &mut App) {
     app.init_resource::<PlayerState>()
-        .insert_resource(levels::LevelData::default());
+        .insert_resource(levels::LevelData::default())
+        .init_resource::<reactions::ReactionTable>()
+        .init_resource::<selling::SellConfig>();
     app.init_state::<PointerInteractionState>();
 
     app
@@ -28,11 +37,15 @@ pub(super) fn plugin(app: &mut App) {
         //.register_type::<AttackEffect>()
         .register_type::<Droplet>()
         .register_type::<Puddle>()
+        .register_type::<Projectile>()
+        .register_type::<ProjectileSpec>()
         .register_type::<LiquidType>()
         .register_type::<AttackSpecification>()
         .register_type::<DamageType>()
         .register_type::<PlayerState>()
+        .register_type::<selling::SellConfig>()
         .register_type::<Tower>()
+        .register_type::<enemies::EnemyArchetype>()
         .register_type::<PointerInteractionState>();
 
     app.add_systems(OnExit(Screen::Loading), validate_assets);
@@ -0,0 +1,326 @@
+use std::fmt::Display;
+
+use crate::level::components::{Adjacent, ExactPosition, WallDirection};
+
+use super::{Tower, projectiles::TowerAttackType};
+
+/// A constraint on where a tower can go. Towers list theirs in `Tower::placement_rules`, and any
+/// tower not listing a rule isn't held to it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PlacementRule {
+    /// The faces the tower can be mounted on
+    Surfaces(&'static [ExactPosition]),
+    /// Can't go on the floor of the bottom row, which has nothing below it
+    NotBottomRow,
+    /// Takes up both sides of its wall or floor, so nothing can share it
+    ExclusiveCell,
+    /// Needs a tower that drops liquid somewhere above its own cell in the same column
+    NeedsLiquidAbove,
+    /// At most this many can be placed in any level. Levels can set tighter limits of their own,
+    /// see `LevelData::tower_limits`.
+    MaxPerLevel(usize),
+}
+
+/// What `can_place` needs to know about the level, gathered up front so the rules can be checked
+/// without touching the ECS
+#[derive(Clone, Debug, Default)]
+pub struct PlacementWorld {
+    pub money: i32,
+    /// Every tower already placed, along with the face it was placed on
+    pub towers: Vec<(Tower, Adjacent)>,
+    /// How many of some towers the current level allows
+    pub level_limits: Vec<(Tower, usize)>,
+}
+
+impl PlacementWorld {
+    /// How many of `tower` can be placed in this level, if it's limited at all
+    pub fn limit(&self, tower: Tower) -> Option<usize> {
+        max_per_level(tower, &self.level_limits)
+    }
+
+    pub fn placed(&self, tower: Tower) -> usize {
+        self.towers
+            .iter()
+            .filter(|(placed, _)| *placed == tower)
+            .count()
+    }
+}
+
+/// The tighter of the tower's own `MaxPerLevel` and the limit a level with `level_limits` sets, or
+/// `None` if neither limits it
+pub fn max_per_level(tower: Tower, level_limits: &[(Tower, usize)]) -> Option<usize> {
+    let level_limit = level_limits
+        .iter()
+        .find(|(limited, _)| *limited == tower)
+        .map(|(_, max)| *max);
+    match (tower.max_per_level(), level_limit) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PlacementError {
+    InsufficientFunds,
+    Occupied,
+    WrongSurface(&'static [ExactPosition]),
+    BottomRow,
+    NeedsBothSides,
+    SharesExclusiveCell,
+    NeedsLiquidAbove,
+    LimitReached(usize),
+}
+
+impl Display for PlacementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlacementError::InsufficientFunds => write!(f, "Insufficient funds"),
+            PlacementError::Occupied => write!(f, "There is already a tower here"),
+            PlacementError::WrongSurface(surfaces) => {
+                let surfaces = surfaces
+                    .iter()
+                    .map(|surface| surface_name(*surface))
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+                write!(f, "This tower must be placed on a {surfaces} panel")
+            }
+            PlacementError::BottomRow => write!(f, "This tower cannot be placed on the bottom"),
+            PlacementError::NeedsBothSides => {
+                write!(f, "This tower requires both sides of the wall")
+            }
+            PlacementError::SharesExclusiveCell => {
+                write!(f, "A tower on the other side is using this spot")
+            }
+            PlacementError::NeedsLiquidAbove => {
+                write!(f, "This tower needs a liquid dropper above it")
+            }
+            PlacementError::LimitReached(max) => {
+                write!(f, "Only {max} of this tower can be placed per level")
+            }
+        }
+    }
+}
+
+fn surface_name(surface: ExactPosition) -> &'static str {
+    match surface {
+        ExactPosition::Floor => "floor",
+        ExactPosition::Ceiling => "ceiling",
+        ExactPosition::Wall(WallDirection::Left) => "left wall",
+        ExactPosition::Wall(WallDirection::Right) => "right wall",
+    }
+}
+
+/// Whether `tower` can go on the face `target`, and if not, why
+pub fn can_place(
+    tower: Tower,
+    target: &Adjacent,
+    world: &PlacementWorld,
+) -> Result<(), PlacementError> {
+    if world.money < tower.price() {
+        return Err(PlacementError::InsufficientFunds);
+    }
+
+    if world.towers.iter().any(|(_, placed)| placed == target) {
+        return Err(PlacementError::Occupied);
+    }
+
+    for rule in tower.placement_rules() {
+        check_rule(rule, target, world)?;
+    }
+
+    if let Some(max) = world.limit(tower).filter(|max| world.placed(tower) >= *max) {
+        return Err(PlacementError::LimitReached(max));
+    }
+
+    // Exclusive towers also keep new towers off the face they've taken
+    if world.towers.iter().any(|(placed, placed_on)| {
+        placed
+            .placement_rules()
+            .contains(&PlacementRule::ExclusiveCell)
+            && placed_on.id == target.id
+    }) {
+        return Err(PlacementError::SharesExclusiveCell);
+    }
+
+    Ok(())
+}
+
+fn check_rule(
+    rule: PlacementRule,
+    target: &Adjacent,
+    world: &PlacementWorld,
+) -> Result<(), PlacementError> {
+    match rule {
+        PlacementRule::Surfaces(surfaces) if !surfaces.contains(&target.exact_position) => {
+            Err(PlacementError::WrongSurface(surfaces))
+        }
+        PlacementRule::NotBottomRow
+            if target.exact_position == ExactPosition::Floor && target.id.unit_y == 0 =>
+        {
+            Err(PlacementError::BottomRow)
+        }
+        PlacementRule::ExclusiveCell
+            if world
+                .towers
+                .iter()
+                .any(|(_, placed)| placed.id == target.id) =>
+        {
+            Err(PlacementError::NeedsBothSides)
+        }
+        PlacementRule::NeedsLiquidAbove => {
+            let cell = target.cell();
+            let liquid_above = world.towers.iter().any(|(placed, placed_on)| {
+                let placed_cell = placed_on.cell();
+                // Towers in the same cell drop liquid beside the tower, not on it
+                placed_cell.x == cell.x
+                    && placed_cell.y > cell.y
+                    && matches!(placed.attack_def(), TowerAttackType::DropsLiquid(_))
+            });
+            if liquid_above {
+                Ok(())
+            } else {
+                Err(PlacementError::NeedsLiquidAbove)
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::components::GeneralPosition;
+
+    fn floor(x: usize, y: usize) -> Adjacent {
+        Adjacent::new(x, y, GeneralPosition::UpDown, ExactPosition::Floor)
+    }
+
+    fn ceiling(x: usize, y: usize) -> Adjacent {
+        Adjacent::new(x, y, GeneralPosition::UpDown, ExactPosition::Ceiling)
+    }
+
+    fn left_wall(x: usize, y: usize) -> Adjacent {
+        Adjacent::new(
+            x,
+            y,
+            GeneralPosition::LeftRight,
+            ExactPosition::Wall(WallDirection::Left),
+        )
+    }
+
+    fn world(towers: Vec<(Tower, Adjacent)>) -> PlacementWorld {
+        PlacementWorld {
+            money: 1000,
+            towers,
+            level_limits: vec![],
+        }
+    }
+
+    #[test]
+    fn places_on_a_free_face() {
+        assert_eq!(
+            can_place(Tower::Piston, &floor(1, 1), &world(vec![])),
+            Ok(())
+        );
+        assert_eq!(
+            can_place(Tower::TrapDoor, &floor(1, 1), &world(vec![])),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn needs_enough_money() {
+        let poor = PlacementWorld {
+            money: Tower::Piston.price() - 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            can_place(Tower::Piston, &floor(1, 1), &poor),
+            Err(PlacementError::InsufficientFunds)
+        );
+    }
+
+    #[test]
+    fn face_can_only_hold_one_tower() {
+        let world = world(vec![(Tower::Fan, left_wall(2, 1))]);
+        assert_eq!(
+            can_place(Tower::Piston, &left_wall(2, 1), &world),
+            Err(PlacementError::Occupied)
+        );
+    }
+
+    #[test]
+    fn trap_door_only_goes_on_floors() {
+        assert!(matches!(
+            can_place(Tower::TrapDoor, &left_wall(1, 1), &world(vec![])),
+            Err(PlacementError::WrongSurface(_))
+        ));
+        assert!(matches!(
+            can_place(Tower::TrapDoor, &ceiling(1, 1), &world(vec![])),
+            Err(PlacementError::WrongSurface(_))
+        ));
+    }
+
+    #[test]
+    fn trap_door_not_on_bottom_row() {
+        assert_eq!(
+            can_place(Tower::TrapDoor, &floor(1, 0), &world(vec![])),
+            Err(PlacementError::BottomRow)
+        );
+    }
+
+    #[test]
+    fn trap_door_needs_both_sides() {
+        let world = world(vec![(Tower::Piston, ceiling(1, 1))]);
+        assert_eq!(
+            can_place(Tower::TrapDoor, &floor(1, 1), &world),
+            Err(PlacementError::NeedsBothSides)
+        );
+    }
+
+    #[test]
+    fn nothing_goes_under_a_trap_door() {
+        let world = world(vec![(Tower::TrapDoor, floor(1, 1))]);
+        assert_eq!(
+            can_place(Tower::Piston, &ceiling(1, 1), &world),
+            Err(PlacementError::SharesExclusiveCell)
+        );
+        // Other faces of the same cells are unaffected
+        assert_eq!(can_place(Tower::Piston, &floor(2, 1), &world), Ok(()));
+    }
+
+    #[test]
+    fn liquid_has_to_come_from_above() {
+        let target = floor(1, 1);
+        let check = |towers| check_rule(PlacementRule::NeedsLiquidAbove, &target, &world(towers));
+
+        assert_eq!(check(vec![]), Err(PlacementError::NeedsLiquidAbove));
+        assert_eq!(check(vec![(Tower::Oil, ceiling(1, 3))]), Ok(()));
+        // The ceiling of the target's own cell isn't above it
+        assert_eq!(
+            check(vec![(Tower::Oil, ceiling(1, 2))]),
+            Err(PlacementError::NeedsLiquidAbove)
+        );
+        assert_eq!(
+            check(vec![(Tower::Oil, ceiling(2, 3))]),
+            Err(PlacementError::NeedsLiquidAbove)
+        );
+        assert_eq!(
+            check(vec![(Tower::Piston, ceiling(1, 3))]),
+            Err(PlacementError::NeedsLiquidAbove)
+        );
+    }
+
+    #[test]
+    fn levels_can_limit_towers() {
+        let mut world = world(vec![(Tower::Piston, left_wall(1, 1))]);
+        world.level_limits = vec![(Tower::Piston, 1)];
+        assert_eq!(
+            can_place(Tower::Piston, &left_wall(2, 1), &world),
+            Err(PlacementError::LimitReached(1))
+        );
+        // The limit is per tower, others can still be placed
+        assert_eq!(world.limit(Tower::Fan), None);
+        assert_eq!(can_place(Tower::Fan, &left_wall(2, 1), &world), Ok(()));
+    }
+}
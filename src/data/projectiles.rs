@@ -10,14 +10,44 @@ pub enum TowerAttackType {
     EntireCell(Vec<AttackSpecification>),
     Contact(Vec<AttackSpecification>),
     DropsLiquid(LiquidType),
+    Projectile(ProjectileSpec),
     ModifiesSelf,
+    /// Never fires. Support towers work through their aura instead.
+    Passive,
 }
 
 #[derive(Clone, Debug, Reflect, PartialEq)]
+pub struct ProjectileSpec {
+    pub speed: f32,
+    /// Multiplier on world gravity. Aimed shots are lobbed to account for it.
+    pub gravity: f32,
+    /// How many enemies a projectile passes through before it's spent
+    pub pierce: usize,
+    /// How many times a projectile can bounce off the level before it's spent
+    pub bounces: usize,
+    pub aim: ProjectileAim,
+    pub payload: Vec<AttackSpecification>,
+}
+
+#[derive(Copy, Clone, Debug, Reflect, PartialEq, Eq)]
+pub enum ProjectileAim {
+    FireDirection,
+    NearestEnemy,
+}
+
+#[derive(Clone, Debug, Reflect, PartialEq)]
+#[reflect(no_field_bounds)]
 pub enum AttackSpecification {
     Damage(DamageType, usize),
     Push(f32),
     Status(StatusEnum),
+    /// Applies `payload` to every enemy within `radius`, weakening by up to `falloff` (0 to 1) at
+    /// the edge. Pushes in the payload shove enemies away from the center.
+    Explosion {
+        radius: f32,
+        falloff: f32,
+        payload: Vec<AttackSpecification>,
+    },
 }
 
 #[derive(Clone, Debug, Reflect, PartialEq)]
@@ -36,14 +66,291 @@ pub enum AttackData {
         status: StatusEnum,
         strength: usize,
     },
+    /// An explosion centered on the target
+    Explosion {
+        radius: f32,
+        falloff: f32,
+        strength: usize,
+        payload: Vec<AttackSpecification>,
+    },
+}
+
+/// Statuses only stick to enemies caught at least this close to full strength by an explosion
+pub const EXPLOSION_STATUS_THRESHOLD: f32 = 0.5;
+
+/// How much of an explosion's payload reaches something `distance` away from its center
+pub fn explosion_scale(distance: f32, radius: f32, falloff: f32) -> f32 {
+    if radius <= 0. {
+        return 1.;
+    }
+    (1. - falloff.clamp(0., 1.) * (distance / radius)).clamp(0., 1.)
+}
+
+impl AttackSpecification {
+    pub fn to_data(&self, direction: CellDirection, strength: usize) -> AttackData {
+        match self {
+            AttackSpecification::Damage(damage_type, damage) => AttackData::Damage {
+                dmg_type: *damage_type,
+                strength,
+                damage: *damage,
+            },
+            AttackSpecification::Push(force) => AttackData::Push {
+                direction,
+                strength,
+                force: *force,
+            },
+            AttackSpecification::Status(status) => AttackData::Status {
+                status: *status,
+                strength,
+            },
+            AttackSpecification::Explosion {
+                radius,
+                falloff,
+                payload,
+            } => AttackData::Explosion {
+                radius: *radius,
+                falloff: *falloff,
+                strength,
+                payload: payload.clone(),
+            },
+        }
+    }
+
+    /// This attack as fired by a tower with the given `TowerDamage` and `StatusStrength` stats
+    pub fn to_tower_data(
+        &self,
+        direction: CellDirection,
+        damage: f32,
+        status_strength: f32,
+    ) -> AttackData {
+        match self {
+            AttackSpecification::Status(_) => {
+                self.to_data(direction, status_strength.round().max(1.) as usize)
+            }
+            _ => self.boosted(damage).to_data(direction, 1),
+        }
+    }
+
+    /// This attack with its damage multiplied by `multiplier`, including inside explosions
+    pub fn boosted(&self, multiplier: f32) -> AttackSpecification {
+        match self {
+            AttackSpecification::Damage(damage_type, damage) => AttackSpecification::Damage(
+                *damage_type,
+                (*damage as f32 * multiplier).round() as usize,
+            ),
+            AttackSpecification::Explosion {
+                radius,
+                falloff,
+                payload,
+            } => AttackSpecification::Explosion {
+                radius: *radius,
+                falloff: *falloff,
+                payload: payload.iter().map(|w| w.boosted(multiplier)).collect(),
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// This attack as felt by something caught `scale` of the way into an explosion, or `None` if
+    /// it doesn't reach
+    pub fn scaled(&self, scale: f32) -> Option<AttackSpecification> {
+        match self {
+            AttackSpecification::Damage(damage_type, damage) => {
+                let damage = (*damage as f32 * scale).round() as usize;
+                (damage > 0).then_some(AttackSpecification::Damage(*damage_type, damage))
+            }
+            AttackSpecification::Push(force) => Some(AttackSpecification::Push(force * scale)),
+            AttackSpecification::Status(_) => {
+                (scale >= EXPLOSION_STATUS_THRESHOLD).then_some(self.clone())
+            }
+            AttackSpecification::Explosion { .. } => Some(self.clone()),
+        }
+    }
 }
 
 #[derive(Component, Copy, Clone, Debug, Reflect, PartialEq, Eq)]
 pub struct Droplet(pub LiquidType);
 
+/// A shot in flight. Lives on the rigid body; enemies are detected by a `ProjectileHitbox` child.
+#[derive(Component, Clone, Debug, Reflect, PartialEq)]
+pub struct Projectile {
+    pub pierce_left: usize,
+    pub bounces_left: usize,
+    pub payload: Vec<AttackSpecification>,
+    /// The firing tower's `StatusStrength` when it fired
+    pub status_strength: f32,
+    pub already_hit: Vec<Entity>,
+    /// Set once the shot has run out of pierce or bounces. Its despawn is only queued, so any
+    /// other collisions it has this frame need to be ignored.
+    pub spent: bool,
+}
+
+impl Projectile {
+    /// `damage` and `status_strength` are the firing tower's stats, baked in when it fires
+    pub fn new(spec: &ProjectileSpec, damage: f32, status_strength: f32) -> Self {
+        Self {
+            pierce_left: spec.pierce,
+            bounces_left: spec.bounces,
+            payload: spec.payload.iter().map(|w| w.boosted(damage)).collect(),
+            status_strength,
+            already_hit: vec![],
+            spent: false,
+        }
+    }
+}
+
+#[derive(Component, Copy, Clone, Debug, Reflect, PartialEq, Eq)]
+pub struct ProjectileHitbox;
+
 #[derive(Component, Copy, Clone, Debug, Reflect, PartialEq, Eq)]
 pub struct Puddle(pub LiquidType);
 
+/// How much liquid a puddle holds. Puddles evaporate as this runs down.
+#[derive(Component, Copy, Clone, Debug, Reflect, PartialEq)]
+pub struct PuddleVolume {
+    pub volume: f32,
+    /// Liquid that sits underneath this one, like water beneath a film of oil. It's left behind
+    /// once the top layer is gone.
+    pub underlayer: Option<(LiquidType, f32)>,
+}
+
+/// Puddles can be set off by the right kind of damage nearby, turning them into hazards for a
+/// while
+#[derive(Copy, Clone, Debug, Reflect, PartialEq, Eq)]
+pub enum PuddleState {
+    Calm,
+    /// Shocks everything standing in it
+    Electrified,
+    /// Ignites everything standing in it, and burns through the liquid quickly
+    Burning,
+    /// A slick patch of ice that doesn't evaporate
+    Frozen,
+}
+
+#[derive(Component, Clone, Debug, Reflect, PartialEq)]
+pub struct PuddleCondition {
+    pub state: PuddleState,
+    pub duration: Timer,
+    pub tick: Timer,
+}
+
+impl Default for PuddleCondition {
+    fn default() -> Self {
+        Self {
+            state: PuddleState::Calm,
+            duration: Timer::default(),
+            tick: Timer::from_seconds(0.5, TimerMode::Repeating),
+        }
+    }
+}
+
+impl PuddleCondition {
+    pub fn set(&mut self, state: PuddleState) {
+        self.state = state;
+        self.duration = Timer::from_seconds(state.duration(), TimerMode::Once);
+        self.tick.reset();
+    }
+}
+
+impl PuddleState {
+    pub fn duration(&self) -> f32 {
+        match self {
+            PuddleState::Calm => 0.,
+            PuddleState::Electrified => 3.,
+            PuddleState::Burning => 4.,
+            PuddleState::Frozen => 6.,
+        }
+    }
+
+    pub fn tint(&self) -> Color {
+        match self {
+            PuddleState::Calm => Color::WHITE,
+            PuddleState::Electrified => css::YELLOW.into(),
+            PuddleState::Burning => css::ORANGE_RED.into(),
+            PuddleState::Frozen => css::LIGHT_CYAN.into(),
+        }
+    }
+}
+
+/// What a puddle of `liquid` turns into when `damage` goes off near it, if anything
+pub fn puddle_reaction(
+    liquid: LiquidType,
+    current: PuddleState,
+    damage: DamageType,
+) -> Option<PuddleState> {
+    match (liquid, current, damage) {
+        (LiquidType::Water, PuddleState::Frozen, DamageType::Burning) => Some(PuddleState::Calm),
+        (_, PuddleState::Frozen, _) => None,
+        (LiquidType::Water, _, DamageType::Lightning) => Some(PuddleState::Electrified),
+        (LiquidType::Water, _, DamageType::Cold) => Some(PuddleState::Frozen),
+        (LiquidType::Oil, _, DamageType::Burning) => Some(PuddleState::Burning),
+        _ => None,
+    }
+}
+
+pub const DROPLET_VOLUME: f32 = 1.0;
+pub const MAX_PUDDLE_VOLUME: f32 = 4.0;
+
+/// What happens when liquid lands in a puddle
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LiquidMix {
+    /// The puddle becomes this liquid with this volume
+    Combined(LiquidType, f32),
+    /// One liquid floats on the other
+    Layered {
+        top: (LiquidType, f32),
+        bottom: (LiquidType, f32),
+    },
+    /// The liquids don't interact and sit side by side
+    Separate,
+}
+
+/// Works out what a puddle of `resting` liquid turns into when `incoming` lands in it
+pub fn mix_liquids(resting: (LiquidType, f32), incoming: (LiquidType, f32)) -> LiquidMix {
+    use LiquidType::*;
+    let ((resting, resting_volume), (incoming, incoming_volume)) = (resting, incoming);
+    let capped = |volume: f32| volume.min(MAX_PUDDLE_VOLUME);
+
+    match (resting, incoming) {
+        (a, b) if a == b => LiquidMix::Combined(a, capped(resting_volume + incoming_volume)),
+        // Water dilutes acid, and enough of it washes the acid out entirely
+        (Acid, Water) | (Water, Acid) => {
+            let (acid, water) = if resting == Acid {
+                (resting_volume, incoming_volume)
+            } else {
+                (incoming_volume, resting_volume)
+            };
+            if water >= acid {
+                LiquidMix::Combined(Water, capped(water - acid))
+            } else {
+                LiquidMix::Combined(Acid, capped(acid - water))
+            }
+        }
+        // Oil floats on water and stays flammable
+        (Oil, Water) | (Water, Oil) => {
+            let (oil, water) = if resting == Oil {
+                (resting_volume, incoming_volume)
+            } else {
+                (incoming_volume, resting_volume)
+            };
+            LiquidMix::Layered {
+                top: (Oil, capped(oil)),
+                bottom: (Water, capped(water)),
+            }
+        }
+        _ => LiquidMix::Separate,
+    }
+}
+
+impl PuddleVolume {
+    pub fn new(volume: f32) -> Self {
+        Self {
+            volume,
+            underlayer: None,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Reflect, PartialEq, Eq)]
 pub enum LiquidType {
     Water,
@@ -51,7 +358,7 @@ pub enum LiquidType {
     Acid,
 }
 
-#[derive(Copy, Clone, Debug, Reflect, PartialEq, Eq, UnsizedConstParamTy)]
+#[derive(Copy, Clone, Debug, Reflect, PartialEq, Eq, Hash, UnsizedConstParamTy)]
 pub enum DamageType {
     Physical,
     Burning,
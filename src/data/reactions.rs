@@ -0,0 +1,181 @@
+use super::{
+    projectiles::{AttackSpecification, DamageType},
+    status_effects::{StatusEnum, damage_multiplier},
+};
+use bevy::prelude::*;
+
+/// What has to hit an enemy for a reaction to go off
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum ReactionTrigger {
+    Status(StatusEnum),
+    Damage(DamageType),
+}
+
+/// "An enemy with `existing` gets hit by `incoming`". Strength and chance functions are given
+/// the strength of the existing status and of the incoming hit, in that order.
+#[derive(Clone, Debug)]
+pub struct Reaction {
+    pub name: &'static str,
+    pub existing: StatusEnum,
+    pub incoming: ReactionTrigger,
+    pub consume_existing: bool,
+    /// Stops an incoming status from being applied. Has no effect on damage.
+    pub consume_incoming: bool,
+    pub product: Option<StatusEnum>,
+    pub strength: fn(usize, usize) -> usize,
+    pub burst: Option<(DamageType, isize)>,
+    /// An `AttackSpecification::Explosion` centered on the reacting enemy
+    pub explosion: Option<AttackSpecification>,
+    pub chance: fn(usize, usize) -> f32,
+}
+
+#[derive(Resource, Clone, Debug)]
+pub struct ReactionTable(pub Vec<Reaction>);
+
+impl Reaction {
+    pub fn new(name: &'static str, existing: StatusEnum, incoming: ReactionTrigger) -> Self {
+        Self {
+            name,
+            existing,
+            incoming,
+            consume_existing: false,
+            consume_incoming: false,
+            product: None,
+            strength: |existing, _| existing,
+            burst: None,
+            explosion: None,
+            chance: |_, _| 1.,
+        }
+    }
+
+    pub fn consumes_existing(mut self) -> Self {
+        self.consume_existing = true;
+        self
+    }
+
+    pub fn consumes_incoming(mut self) -> Self {
+        self.consume_incoming = true;
+        self
+    }
+
+    pub fn produces(mut self, product: StatusEnum, strength: fn(usize, usize) -> usize) -> Self {
+        self.product = Some(product);
+        self.strength = strength;
+        self
+    }
+
+    pub fn bursts(mut self, damage_type: DamageType, damage: isize) -> Self {
+        self.burst = Some((damage_type, damage));
+        self
+    }
+
+    pub fn explodes(
+        mut self,
+        radius: f32,
+        falloff: f32,
+        payload: Vec<AttackSpecification>,
+    ) -> Self {
+        self.explosion = Some(AttackSpecification::Explosion {
+            radius,
+            falloff,
+            payload,
+        });
+        self
+    }
+
+    pub fn with_chance(mut self, chance: fn(usize, usize) -> f32) -> Self {
+        self.chance = chance;
+        self
+    }
+}
+
+impl ReactionTable {
+    pub fn matching(
+        &self,
+        existing: StatusEnum,
+        incoming: ReactionTrigger,
+    ) -> impl Iterator<Item = &Reaction> {
+        self.0
+            .iter()
+            .filter(move |r| r.existing == existing && r.incoming == incoming)
+    }
+}
+
+impl Default for ReactionTable {
+    fn default() -> Self {
+        use DamageType as D;
+        use ReactionTrigger::{Damage, Status};
+        use StatusEnum as S;
+
+        Self(vec![
+            Reaction::new("Freeze", S::Wet, Status(S::Chilled))
+                .consumes_existing()
+                .produces(S::Frozen, |_, _| 2),
+            Reaction::new("Ignite", S::Oiled, Status(S::Burned))
+                .consumes_existing()
+                .consumes_incoming()
+                .produces(S::Ignited, |oil, _| oil + 1),
+            Reaction::new("Spark", S::Oiled, Damage(D::Lightning))
+                .consumes_existing()
+                .produces(S::Ignited, |oil, shock| oil + shock),
+            Reaction::new("Conduct", S::Wet, Damage(D::Lightning))
+                .produces(S::Electrocuted, |wet, shock| {
+                    1.max((wet + shock).saturating_sub(1))
+                })
+                .with_chance(|wet, shock| damage_multiplier(shock + wet) * 0.1),
+            Reaction::new("Thaw", S::Frozen, Damage(D::Burning))
+                .consumes_existing()
+                .produces(S::Wet, |ice, _| ice),
+            Reaction::new("Dilute", S::Acidified, Status(S::Wet))
+                .consumes_existing()
+                .produces(S::Acidified, |acid, _| acid / 2),
+            Reaction::new("Corrode", S::Acidified, Damage(D::Lightning))
+                .consumes_existing()
+                .bursts(D::Chemical, 25),
+            Reaction::new("Daze", S::Electrocuted, Status(S::KnockedBack))
+                .produces(S::Stunned, |shock, _| shock),
+            Reaction::new("Congeal", S::Oiled, Status(S::Chilled))
+                .produces(S::Rooted, |oil, cold| oil.max(cold)),
+            Reaction::new("Shatter", S::Frozen, Status(S::KnockedBack))
+                .consumes_existing()
+                .bursts(D::Physical, 30),
+            Reaction::new("Flashover", S::Ignited, Status(S::Oiled))
+                .consumes_existing()
+                .consumes_incoming()
+                .explodes(
+                    14.,
+                    0.6,
+                    vec![
+                        AttackSpecification::Damage(D::Burning, 20),
+                        AttackSpecification::Push(60.),
+                        AttackSpecification::Status(S::Burned),
+                    ],
+                ),
+            Reaction::new("Fumes", S::Ignited, Status(S::Acidified))
+                .produces(S::Confused, |_, acid| acid),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pushing_frozen_enemies_shatters_them() {
+        let table = ReactionTable::default();
+        let mut shatters = table.matching(
+            StatusEnum::Frozen,
+            ReactionTrigger::Status(StatusEnum::KnockedBack),
+        );
+        let shatter = shatters.next().expect("Frozen enemies should shatter");
+        assert!(shatters.next().is_none());
+
+        assert!(shatter.consume_existing);
+        assert!(!shatter.consume_incoming);
+        assert!(matches!(
+            shatter.burst,
+            Some((DamageType::Physical, damage)) if damage > 0
+        ));
+    }
+}
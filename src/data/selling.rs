@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+/// How much of a tower's cost comes back when it's sold
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct SellConfig {
+    /// Fraction of the invested money refunded once the grace period is over
+    pub refund_ratio: f32,
+    /// Seconds after placement during which selling refunds everything
+    pub grace_period: f32,
+    /// Seconds after selling during which the sale can be undone
+    pub undo_window: f32,
+}
+
+impl Default for SellConfig {
+    fn default() -> Self {
+        Self {
+            refund_ratio: 0.7,
+            grace_period: 5.0,
+            undo_window: 5.0,
+        }
+    }
+}
+
+impl SellConfig {
+    pub fn refund(&self, invested: i32, full_refund: bool) -> i32 {
+        if full_refund {
+            invested
+        } else {
+            (invested as f32 * self.refund_ratio).floor() as i32
+        }
+    }
+}
@@ -39,6 +39,16 @@ impl<T: StatTrait> Stat<T> {
         self.cached_value
     }
 
+    pub fn base(&self) -> f32 {
+        self.base
+    }
+
+    /// Every modifier applied this frame, as (pre-multiplier bonuses, multipliers, post-multiplier
+    /// bonuses)
+    pub fn modifiers(&self) -> (&[f32], &[f32], &[f32]) {
+        (&self.pre_flat, &self.multipliers, &self.post_flat)
+    }
+
     pub fn current_value_recalculate(&mut self) -> f32 {
         if self.changed {
             self.recalculate();
@@ -82,6 +92,12 @@ impl<T: StatTrait> Stat<T> {
 define_stat!(MoveSpeed, "Move Speed");
 define_stat!(StatFriction, "Friction");
 define_stat!(DamageMultiplierAll, "Global Damage Multiplier");
+define_stat!(Armor, "Armor");
+define_stat!(TowerCooldown, "Cooldown");
+define_stat!(TowerDamage, "Damage Multiplier");
+define_stat!(TriggerRange, "Trigger Range");
+define_stat!(StatusStrength, "Status Strength");
+define_stat!(ExtraShots, "Extra Shots");
 
 #[derive(Component, Copy, Clone, Eq, PartialEq, Hash, Debug, Reflect)]
 pub struct DamageMultiplier<const G: DamageType>;
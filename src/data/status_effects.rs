@@ -1,6 +1,6 @@
-use crate::define_status_effect;
+use crate::{assets::StatusSheet, define_status_effect, define_status_roster};
 use bevy::{color::palettes::css::*, prelude::*, reflect::GetTypeRegistration};
-use std::marker::PhantomData;
+use std::{marker::PhantomData, time::Duration};
 
 use super::projectiles::DamageType;
 
@@ -18,49 +18,302 @@ pub trait StatusEffectTrait:
         StatusEffect::new(strength, duration)
     }
     fn corresponding_enum() -> StatusEnum;
+    fn stacking() -> StackingPolicy {
+        StackingPolicy::KeepStrongest
+    }
+    fn sprite_sheet() -> StatusSheet;
+    fn animation_frames() -> &'static [usize];
+
+    /// Runs once when the status is first put on an enemy
+    fn on_apply(_commands: &mut Commands, _enemy: Entity, _strength: usize) {}
+    /// Runs every frame while the status is active
+    fn on_tick(_commands: &mut Commands, _enemy: Entity, _strength: usize) {}
+    /// Runs when the status is taken off, whether it timed out or was removed
+    fn on_remove(_commands: &mut Commands, _enemy: Entity, _strength: usize) {}
+}
+
+/// What happens when a status is applied to an enemy that already has it
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum StackingPolicy {
+    /// A stronger application replaces the current one, an equal one refreshes it, and a weaker
+    /// one is ignored
+    KeepStrongest,
+    /// Strengths add together up to `cap`, and the duration restarts at the combined strength
+    AddStrength { cap: usize },
+    /// The strongest application wins and the timer restarts
+    RefreshDuration,
+    /// The new duration is added on to whatever is left, up to `max` seconds
+    ExtendDuration { max: f32 },
+    /// Every application ticks down on its own, and the strength is their total. The oldest stack
+    /// is dropped once there are `max_stacks`
+    Independent { max_stacks: usize },
 }
 
 #[derive(Component, Eq, PartialEq, Debug, Reflect, Clone)]
 pub struct StatusEffect<T: StatusEffectTrait> {
     pub strength: usize,
+    /// Unused by `StackingPolicy::Independent`, where every stack has a timer of its own. Use
+    /// `timer` to see how long the status has left whatever its policy.
     pub duration: Timer,
+    /// Only used by `StackingPolicy::Independent`
+    stacks: Vec<(usize, Timer)>,
     #[reflect(ignore)]
     _phantom: PhantomData<T>,
 }
 
 impl<T: StatusEffectTrait> StatusEffect<T> {
     pub fn new(strength: usize, duration: f32) -> StatusEffect<T> {
+        let duration = Timer::from_seconds(duration, TimerMode::Once);
         StatusEffect {
             strength,
-            duration: Timer::from_seconds(duration, TimerMode::Once),
+            stacks: match T::stacking() {
+                StackingPolicy::Independent { .. } => vec![(strength, duration.clone())],
+                _ => Vec::new(),
+            },
+            duration,
             _phantom: PhantomData,
         }
     }
+
+    pub fn stack_count(&self) -> usize {
+        self.stacks.len().max(1)
+    }
+
+    /// The timer of the longest-lived stack, or `duration` for statuses that don't stack
+    /// independently
+    pub fn timer(&self) -> &Timer {
+        self.stacks
+            .iter()
+            .map(|(_, timer)| timer)
+            .max_by_key(|timer| timer.remaining())
+            .unwrap_or(&self.duration)
+    }
+
+    /// Whether the status has run out. Independent stacks run out one by one, and the status
+    /// with the last of them.
+    pub fn finished(&self) -> bool {
+        match T::stacking() {
+            StackingPolicy::Independent { .. } => self.stacks.is_empty(),
+            _ => self.duration.finished(),
+        }
+    }
+
+    /// Combines another application of this status into the existing one, following
+    /// `T::stacking()`. `duration_for` gives the full duration for a given strength.
+    pub fn merge(&mut self, strength: usize, duration: f32, duration_for: impl Fn(usize) -> f32) {
+        match T::stacking() {
+            StackingPolicy::KeepStrongest => {
+                if strength > self.strength {
+                    self.strength = strength;
+                    self.restart(duration);
+                } else if strength == self.strength {
+                    self.restart(duration.max(self.duration.remaining_secs()));
+                }
+            }
+            StackingPolicy::AddStrength { cap } => {
+                self.strength = (self.strength + strength).min(cap);
+                self.restart(duration_for(self.strength));
+            }
+            StackingPolicy::RefreshDuration => {
+                self.strength = self.strength.max(strength);
+                self.restart(duration.max(self.duration.remaining_secs()));
+            }
+            StackingPolicy::ExtendDuration { max } => {
+                self.strength = self.strength.max(strength);
+                self.restart((self.duration.remaining_secs() + duration).min(max));
+            }
+            StackingPolicy::Independent { max_stacks } => {
+                if self.stacks.len() >= max_stacks.max(1) {
+                    self.stacks.remove(0);
+                }
+                self.stacks
+                    .push((strength, Timer::from_seconds(duration, TimerMode::Once)));
+                self.sync_stacks();
+            }
+        }
+    }
+
+    pub fn tick(&mut self, delta: Duration) {
+        if self.stacks.is_empty() {
+            self.duration.tick(delta);
+            return;
+        }
+        for (_, timer) in self.stacks.iter_mut() {
+            timer.tick(delta);
+        }
+        self.stacks.retain(|(_, timer)| !timer.finished());
+        self.sync_stacks();
+    }
+
+    fn restart(&mut self, duration: f32) {
+        self.duration
+            .set_duration(Duration::from_secs_f32(duration));
+        self.duration.reset();
+    }
+
+    /// Keeps the overall strength in line with the stacks that are still running
+    fn sync_stacks(&mut self) {
+        self.strength = self.stacks.iter().map(|(strength, _)| strength).sum();
+    }
 }
 
-define_status_effect!(Wet, "Wet", BLUE.into(), 4., DamageType::Cold);
-define_status_effect!(Ignited, "Ignited", RED.into(), 3., DamageType::Burning);
-define_status_effect!(Burned, "Burned", ORANGE.into(), 4., DamageType::Burning);
-define_status_effect!(Chilled, "Chilled", AQUA.into(), 3.0, DamageType::Cold);
-define_status_effect!(Frozen, "Frozen", AQUA.into(), 3.0, DamageType::Cold);
+define_status_effect!(
+    Wet,
+    "Wet",
+    BLUE.into(),
+    4.,
+    DamageType::Cold,
+    StatusSheet::Wet,
+    &[0, 1, 2, 3, 4, 5],
+    {
+        fn stacking() -> StackingPolicy {
+            StackingPolicy::RefreshDuration
+        }
+    }
+);
+define_status_effect!(
+    Ignited,
+    "Ignited",
+    RED.into(),
+    3.,
+    DamageType::Burning,
+    StatusSheet::Burning,
+    &[0, 1, 2, 3, 4, 5]
+);
+define_status_effect!(
+    Burned,
+    "Burned",
+    ORANGE.into(),
+    4.,
+    DamageType::Burning,
+    StatusSheet::Burning,
+    &[0, 1, 2, 3, 4, 5],
+    {
+        fn stacking() -> StackingPolicy {
+            StackingPolicy::AddStrength { cap: 3 }
+        }
+    }
+);
+define_status_effect!(
+    Chilled,
+    "Chilled",
+    AQUA.into(),
+    3.0,
+    DamageType::Cold,
+    StatusSheet::Frozen,
+    &[0, 1, 2, 3, 4, 5]
+);
+define_status_effect!(
+    Frozen,
+    "Frozen",
+    AQUA.into(),
+    3.0,
+    DamageType::Cold,
+    StatusSheet::Frozen,
+    &[0, 2, 4]
+);
 define_status_effect!(
     Electrocuted,
     "Electrocuted",
     YELLOW.into(),
     0.9,
-    DamageType::Lightning
+    DamageType::Lightning,
+    StatusSheet::Electrified,
+    &[0, 2, 4],
+    {
+        fn on_remove(commands: &mut Commands, enemy: Entity, strength: usize) {
+            commands.send_event(Discharge { enemy, strength });
+        }
+    }
 );
 define_status_effect!(
     Acidified,
     "Acidified",
     LIME.into(),
     4.0,
-    DamageType::Chemical
+    DamageType::Chemical,
+    StatusSheet::Acidic,
+    &[0, 1, 2, 3, 4, 5, 6, 6, 6, 6],
+    {
+        fn stacking() -> StackingPolicy {
+            StackingPolicy::Independent { max_stacks: 4 }
+        }
+    }
+);
+define_status_effect!(
+    Oiled,
+    "Oiled",
+    BROWN.into(),
+    4.0,
+    DamageType::Chemical,
+    StatusSheet::Oiled,
+    &[0, 1, 2, 3, 4, 5],
+    {
+        fn stacking() -> StackingPolicy {
+            StackingPolicy::ExtendDuration { max: 10.0 }
+        }
+    }
 );
-define_status_effect!(Oiled, "Oiled", BROWN.into(), 4.0, DamageType::Chemical);
 
-#[derive(Clone, Copy, Debug, Reflect, PartialEq, Eq)]
-pub enum StatusEnum {
+define_status_effect!(
+    Stunned,
+    "Stunned",
+    GOLD.into(),
+    1.0,
+    DamageType::Lightning,
+    StatusSheet::Electrified,
+    &[1, 3, 5]
+);
+define_status_effect!(
+    Rooted,
+    "Rooted",
+    OLIVE.into(),
+    2.0,
+    DamageType::Physical,
+    StatusSheet::Slowed,
+    &[0]
+);
+define_status_effect!(
+    Confused,
+    "Confused",
+    VIOLET.into(),
+    2.5,
+    DamageType::Chemical,
+    StatusSheet::Pushed,
+    &[0]
+);
+define_status_effect!(
+    KnockedBack,
+    "Knocked Back",
+    WHITE.into(),
+    // Ends when the enemy lands, this only catches enemies that never do
+    2.0,
+    DamageType::Physical,
+    StatusSheet::Pushed,
+    &[0],
+    {
+        fn stacking() -> StackingPolicy {
+            StackingPolicy::RefreshDuration
+        }
+    }
+);
+
+define_status_effect!(
+    Cooked,
+    "Cooked",
+    SADDLE_BROWN.into(),
+    5.0,
+    DamageType::Burning,
+    StatusSheet::Burning,
+    &[5],
+    {
+        fn stacking() -> StackingPolicy {
+            StackingPolicy::RefreshDuration
+        }
+    }
+);
+
+define_status_roster!(
     Wet,
     Ignited,
     Burned,
@@ -69,11 +322,92 @@ pub enum StatusEnum {
     Electrocuted,
     Acidified,
     Oiled,
+    Stunned,
+    Rooted,
+    Confused,
+    KnockedBack,
+    Cooked
+);
+
+/// Extra strength Cold hits get against wet enemies
+pub const WET_COLD_BONUS: usize = 1;
+
+/// Strength of a hit of `element` after accounting for the target being wet. Water conducts the
+/// cold straight into enemies, so Cold hits land harder.
+pub fn empowered_strength(element: DamageType, strength: usize, wet: bool) -> usize {
+    match element {
+        DamageType::Cold if wet => strength + WET_COLD_BONUS,
+        _ => strength,
+    }
+}
+
+/// Strength of the `Cooked` a burn of `burn_strength` leaves behind when it ends, if any. Burns
+/// only cook enemies without oil on them, oiled ones are left to ignite.
+pub fn cooked_after_burn(burn_strength: usize, oiled: bool) -> Option<usize> {
+    (!oiled && burn_strength > 0).then_some(burn_strength)
+}
+
+/// Sent when an electrocuted enemy stops being electrocuted, shocking everything around it
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub struct Discharge {
+    pub enemy: Entity,
+    pub strength: usize,
+}
+
+/// How an enemy shrugs off a particular status
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum Resistance {
+    Immune,
+    /// Takes `strength` off every application and scales the duration of whatever gets through
+    Reduced {
+        strength: usize,
+        duration: f32,
+    },
+}
+
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+pub struct StatusResistances(pub Vec<(StatusEnum, Resistance)>);
+
+impl StatusResistances {
+    pub fn immune(mut self, statuses: &[StatusEnum]) -> Self {
+        self.0
+            .extend(statuses.iter().map(|status| (*status, Resistance::Immune)));
+        self
+    }
+
+    pub fn resist(mut self, statuses: &[StatusEnum], strength: usize, duration: f32) -> Self {
+        self.0.extend(
+            statuses
+                .iter()
+                .map(|status| (*status, Resistance::Reduced { strength, duration })),
+        );
+        self
+    }
+
+    /// The strength and duration multiplier that make it through, or `None` if the status is
+    /// resisted entirely
+    pub fn resolve(&self, status: StatusEnum, strength: usize) -> Option<(usize, f32)> {
+        let mut strength = strength;
+        let mut duration = 1.;
+        for (_, resistance) in self.0.iter().filter(|(s, _)| *s == status) {
+            match resistance {
+                Resistance::Immune => return None,
+                Resistance::Reduced {
+                    strength: reduction,
+                    duration: multiplier,
+                } => {
+                    strength = strength.saturating_sub(*reduction);
+                    duration *= multiplier;
+                }
+            }
+        }
+        (strength > 0).then_some((strength, duration))
+    }
 }
 
 #[macro_export]
 macro_rules! define_status_effect {
-    ( $structname:ident, $name:expr, $color:expr , $base_duration: expr, $element: expr) => {
+    ( $structname:ident, $name:expr, $color:expr , $base_duration: expr, $element: expr, $sheet: expr, $frames: expr $(, { $($behaviour:tt)* })? ) => {
         #[derive(Component, Copy, Clone, Eq, PartialEq, Hash, Debug, Reflect)]
         pub struct $structname;
 
@@ -97,6 +431,64 @@ macro_rules! define_status_effect {
             fn corresponding_enum() -> StatusEnum {
                 StatusEnum::$structname
             }
+
+            fn sprite_sheet() -> StatusSheet {
+                $sheet
+            }
+
+            fn animation_frames() -> &'static [usize] {
+                $frames
+            }
+
+            $($($behaviour)*)?
+        }
+    };
+}
+
+/// Builds `StatusEnum` out of every status type, along with lookups that forward to each type's
+/// `StatusEffectTrait` impl
+#[macro_export]
+macro_rules! define_status_roster {
+    ( $($structname:ident),* $(,)? ) => {
+        #[derive(Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+        pub enum StatusEnum {
+            $($structname,)*
+        }
+
+        impl StatusEnum {
+            pub fn all() -> Vec<StatusEnum> {
+                vec![$(StatusEnum::$structname,)*]
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(StatusEnum::$structname => $structname::name(),)*
+                }
+            }
+
+            pub fn color(&self) -> Color {
+                match self {
+                    $(StatusEnum::$structname => $structname::color(),)*
+                }
+            }
+
+            pub fn sprite_sheet(&self) -> StatusSheet {
+                match self {
+                    $(StatusEnum::$structname => $structname::sprite_sheet(),)*
+                }
+            }
+
+            pub fn animation_frames(&self) -> &'static [usize] {
+                match self {
+                    $(StatusEnum::$structname => $structname::animation_frames(),)*
+                }
+            }
+
+            pub fn damage_element(&self) -> DamageType {
+                match self {
+                    $(StatusEnum::$structname => $structname::damage_element(),)*
+                }
+            }
         }
     };
 }
@@ -122,3 +514,152 @@ pub fn damage_multiplier(strength: usize) -> f32 {
         _ => 4.,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_remaining<T: StatusEffectTrait>(status: &StatusEffect<T>, secs: f32) {
+        let remaining = status.timer().remaining_secs();
+        assert!(
+            (remaining - secs).abs() < 1e-3,
+            "expected {secs}s left, found {remaining}s"
+        );
+    }
+
+    #[test]
+    fn wet_enemies_take_stronger_cold_hits() {
+        assert_eq!(
+            empowered_strength(DamageType::Cold, 1, true),
+            1 + WET_COLD_BONUS
+        );
+        assert_eq!(empowered_strength(DamageType::Cold, 1, false), 1);
+        assert_eq!(empowered_strength(DamageType::Lightning, 1, true), 1);
+    }
+
+    #[test]
+    fn burns_only_cook_without_oil() {
+        assert_eq!(cooked_after_burn(2, false), Some(2));
+        assert_eq!(cooked_after_burn(2, true), None);
+        assert_eq!(cooked_after_burn(0, false), None);
+    }
+
+    #[test]
+    fn keep_strongest_ignores_weaker_hits() {
+        let mut chilled = StatusEffect::<Chilled>::new(3, 5.);
+        chilled.tick(Duration::from_secs(1));
+        chilled.merge(1, 1., duration_multiplier);
+        assert_eq!(chilled.strength, 3);
+        assert_remaining(&chilled, 4.);
+        assert_eq!(chilled.stack_count(), 1);
+    }
+
+    #[test]
+    fn keep_strongest_replaces_with_stronger_and_refreshes_equal() {
+        let mut chilled = StatusEffect::<Chilled>::new(1, 5.);
+        chilled.tick(Duration::from_secs(4));
+        chilled.merge(1, 3., duration_multiplier);
+        assert_eq!(chilled.strength, 1);
+        assert_remaining(&chilled, 3.);
+
+        chilled.merge(2, 2., duration_multiplier);
+        assert_eq!(chilled.strength, 2);
+        assert_remaining(&chilled, 2.);
+        assert_eq!(chilled.stack_count(), 1);
+    }
+
+    #[test]
+    fn add_strength_adds_up_to_cap() {
+        let StackingPolicy::AddStrength { cap } = Burned::stacking() else {
+            panic!("Burned should add strength");
+        };
+        let mut burned = StatusEffect::<Burned>::new(1, duration_multiplier(1));
+        burned.merge(1, duration_multiplier(1), duration_multiplier);
+        assert_eq!(burned.strength, 2);
+        assert_remaining(&burned, duration_multiplier(2));
+
+        burned.merge(cap, duration_multiplier(cap), duration_multiplier);
+        assert_eq!(burned.strength, cap);
+        assert_remaining(&burned, duration_multiplier(cap));
+        assert_eq!(burned.stack_count(), 1);
+    }
+
+    #[test]
+    fn refresh_duration_keeps_strongest_and_longest() {
+        let mut wet = StatusEffect::<Wet>::new(2, 4.);
+        wet.tick(Duration::from_secs(3));
+        wet.merge(1, 2., duration_multiplier);
+        assert_eq!(wet.strength, 2);
+        assert_remaining(&wet, 2.);
+
+        // A shorter hit doesn't cut the timer down
+        wet.merge(1, 0.5, duration_multiplier);
+        assert_eq!(wet.strength, 2);
+        assert_remaining(&wet, 2.);
+        assert_eq!(wet.stack_count(), 1);
+    }
+
+    #[test]
+    fn extend_duration_adds_time_up_to_max() {
+        let StackingPolicy::ExtendDuration { max } = Oiled::stacking() else {
+            panic!("Oiled should extend its duration");
+        };
+        let mut oiled = StatusEffect::<Oiled>::new(1, 4.);
+        oiled.tick(Duration::from_secs(1));
+        oiled.merge(2, 4., duration_multiplier);
+        assert_eq!(oiled.strength, 2);
+        assert_remaining(&oiled, 7.);
+
+        oiled.merge(1, max, duration_multiplier);
+        assert_eq!(oiled.strength, 2);
+        assert_remaining(&oiled, max);
+        assert_eq!(oiled.stack_count(), 1);
+    }
+
+    #[test]
+    fn independent_stacks_tick_down_on_their_own() {
+        let mut acid = StatusEffect::<Acidified>::new(1, 4.);
+        acid.tick(Duration::from_secs(1));
+        acid.merge(2, 2., duration_multiplier);
+        assert_eq!(acid.strength, 3);
+        assert_eq!(acid.stack_count(), 2);
+        assert_remaining(&acid, 3.);
+
+        // The weaker, older stack outlives the stronger one
+        acid.tick(Duration::from_secs(2));
+        assert_eq!(acid.strength, 1);
+        assert_eq!(acid.stack_count(), 1);
+        assert_remaining(&acid, 1.);
+
+        acid.tick(Duration::from_secs(1));
+        assert_eq!(acid.strength, 0);
+        assert!(acid.finished());
+    }
+
+    #[test]
+    fn independent_stacks_leave_duration_alone() {
+        let mut acid = StatusEffect::<Acidified>::new(1, 4.);
+        acid.tick(Duration::from_secs(1));
+        acid.merge(1, 2., duration_multiplier);
+        acid.tick(Duration::from_secs(1));
+        assert_eq!(acid.duration.elapsed_secs(), 0.);
+        // The full length of the longest-lived stack is still known, not just what it has left
+        assert_eq!(acid.timer().duration().as_secs_f32(), 4.);
+        assert_remaining(&acid, 2.);
+        assert!(!acid.finished());
+    }
+
+    #[test]
+    fn independent_drops_oldest_stack_past_max() {
+        let StackingPolicy::Independent { max_stacks } = Acidified::stacking() else {
+            panic!("Acidified should stack independently");
+        };
+        let mut acid = StatusEffect::<Acidified>::new(5, 1.);
+        for _ in 0..max_stacks {
+            acid.merge(1, 4., duration_multiplier);
+        }
+        assert_eq!(acid.stack_count(), max_stacks);
+        assert_eq!(acid.strength, max_stacks);
+        assert_remaining(&acid, 4.);
+    }
+}
@@ -2,10 +2,19 @@ use std::sync::Arc;
 
 use bevy::prelude::*;
 
-use crate::assets::{SoundEffects, sound_effects::SoundFn};
+use crate::{
+    assets::{SoundEffects, sound_effects::SoundFn},
+    level::{
+        components::{ExactPosition, LEVEL_SCALING},
+        resource::{CellDirection, Level},
+    },
+};
 
 use super::{
-    projectiles::{AttackSpecification, DamageType, LiquidType, TowerAttackType},
+    placement::PlacementRule,
+    projectiles::{
+        AttackSpecification, DamageType, LiquidType, ProjectileAim, ProjectileSpec, TowerAttackType,
+    },
     status_effects::StatusEnum,
 };
 
@@ -21,9 +30,131 @@ pub enum Tower {
     Tesla,
     Water,
     Flame,
+    Mortar,
+    Overclocker,
+    Amplifier,
+    Generator,
     Portal,
 }
 
+/// Size of the trigger zone for towers that only cover their own cell
+pub const CELL_AREA: Vec2 = Vec2::new(9., 9.);
+
+/// The shape of the area a tower covers. Some shapes depend on the level around the tower, see
+/// [`TowerArea::rect`].
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+pub enum TowerArea {
+    /// A box centered on the tower
+    Box(Vec2),
+    /// The tower's cell and everything straight below it, down to the first floor. Towers that
+    /// drop things cover this.
+    ColumnBelow,
+    /// Everything in front of the tower up to the first wall, like a fan's airflow
+    LineToWall,
+    /// Support towers work on other towers rather than covering an area
+    None,
+}
+
+impl TowerArea {
+    /// The world space area covered by a tower in `cell` facing `facing`, scaled by its
+    /// `TriggerRange`. Columns only grow sideways, since their height comes from the floor.
+    pub fn rect(
+        &self,
+        cell: IVec2,
+        facing: CellDirection,
+        range: f32,
+        level: &Level,
+    ) -> Option<Rect> {
+        let center = cell.as_vec2() * LEVEL_SCALING;
+        match self {
+            TowerArea::Box(size) => Some(Rect::from_center_size(center, *size * range)),
+            TowerArea::ColumnBelow => {
+                let floor = (0..=cell.y)
+                    .rev()
+                    .find(|y| level.has_floor(cell.x, *y))
+                    .unwrap_or(0);
+                let bottom = (floor as f32 - 0.5) * LEVEL_SCALING;
+                let top = center.y + LEVEL_SCALING / 2.;
+                let half_width = LEVEL_SCALING / 2. * range;
+                Some(Rect::new(
+                    center.x - half_width,
+                    bottom,
+                    center.x + half_width,
+                    top,
+                ))
+            }
+            TowerArea::LineToWall => {
+                let step = facing.vec().as_ivec2();
+                let mut last = cell;
+                while !level.blocked(last, facing) {
+                    last += step;
+                }
+                let across = facing.clockwise().vec().abs() * CELL_AREA.x / 2.;
+                let along = facing.vec() * LEVEL_SCALING / 2.;
+                let far_end = last.as_vec2() * LEVEL_SCALING + along;
+                let near_end = center - along;
+                Some(Rect::from_corners(near_end - across, far_end + across))
+            }
+            TowerArea::None => None,
+        }
+    }
+}
+
+/// What a tower works with, used to group towers in the hotbar
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum TowerElement {
+    Mechanical,
+    Liquid,
+    Thermal,
+    Electric,
+}
+
+impl TowerElement {
+    pub fn all() -> [TowerElement; 4] {
+        [
+            TowerElement::Mechanical,
+            TowerElement::Liquid,
+            TowerElement::Thermal,
+            TowerElement::Electric,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TowerElement::Mechanical => "Mechanical",
+            TowerElement::Liquid => "Liquid",
+            TowerElement::Thermal => "Thermal",
+            TowerElement::Electric => "Electric",
+        }
+    }
+}
+
+/// A support tower's effect on the towers around it
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+pub struct Aura {
+    /// How many cells out the aura reaches, counting diagonals. 1 covers the 3x3 block around
+    /// the tower's own cell.
+    pub radius: i32,
+    pub effect: AuraEffect,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+pub enum AuraEffect {
+    CooldownMultiplier(f32),
+    StatusStrengthBonus(f32),
+    /// Towers of this type fire one extra shot each time they fire
+    ExtraShot(Tower),
+}
+
+impl Aura {
+    pub fn affects(&self, tower: &Tower) -> bool {
+        match self.effect {
+            AuraEffect::ExtraShot(target) => target == *tower,
+            _ => tower.aura().is_none(),
+        }
+    }
+}
+
 impl Tower {
     pub fn all() -> Vec<Tower> {
         vec![
@@ -37,9 +168,58 @@ impl Tower {
             Tower::Tesla,
             Tower::Water,
             Tower::Flame,
+            Tower::Mortar,
+            Tower::Overclocker,
+            Tower::Amplifier,
+            Tower::Generator,
         ]
     }
 
+    pub fn aura(&self) -> Option<Aura> {
+        match self {
+            Tower::Overclocker => Some(Aura {
+                radius: 1,
+                effect: AuraEffect::CooldownMultiplier(0.75),
+            }),
+            Tower::Amplifier => Some(Aura {
+                radius: 1,
+                effect: AuraEffect::StatusStrengthBonus(1.),
+            }),
+            Tower::Generator => Some(Aura {
+                radius: 2,
+                effect: AuraEffect::ExtraShot(Tower::Tesla),
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn element(&self) -> TowerElement {
+        match self {
+            Tower::Piston
+            | Tower::Fan
+            | Tower::SpikePit
+            | Tower::TrapDoor
+            | Tower::Mortar
+            | Tower::Portal => TowerElement::Mechanical,
+            Tower::Oil | Tower::Acid | Tower::Water => TowerElement::Liquid,
+            Tower::Ice | Tower::Flame => TowerElement::Thermal,
+            Tower::Tesla | Tower::Overclocker | Tower::Amplifier | Tower::Generator => {
+                TowerElement::Electric
+            }
+        }
+    }
+
+    /// Towers without art of their own borrow another tower's, tinted so they can be told apart
+    pub fn sprite_tint(&self) -> Option<Color> {
+        match self {
+            Tower::Mortar => Some(Color::srgb(0.55, 0.7, 0.45)),
+            Tower::Overclocker => Some(Color::srgb(1.0, 0.6, 0.6)),
+            Tower::Amplifier => Some(Color::srgb(0.8, 0.6, 1.0)),
+            Tower::Generator => Some(Color::srgb(1.0, 0.9, 0.4)),
+            _ => None,
+        }
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             Tower::Piston => "Piston",
@@ -52,6 +232,10 @@ impl Tower {
             Tower::Tesla => "Tesla Turret",
             Tower::Water => "Water Bucket",
             Tower::Flame => "Flame",
+            Tower::Mortar => "Mortar",
+            Tower::Overclocker => "Overclocker",
+            Tower::Amplifier => "Amplifier",
+            Tower::Generator => "Generator",
             Tower::Portal => "Portal",
         }
     }
@@ -70,6 +254,10 @@ impl Tower {
             Tower::Flame => {
                 "Burns enemies, ignites oil. Enemies cooked without oil are weak to cold."
             }
+            Tower::Mortar => "Lobs shells at the nearest enemy from a distance.",
+            Tower::Overclocker => "Adjacent towers fire 25% faster.",
+            Tower::Amplifier => "Adjacent towers apply stronger statuses.",
+            Tower::Generator => "Tesla Turrets within two cells fire twice.",
             Tower::Portal => "Teleports enemies backward along their path.",
         }
     }
@@ -86,6 +274,10 @@ impl Tower {
             Tower::Tesla => 40,
             Tower::Water => 50,
             Tower::Flame => 40,
+            Tower::Mortar => 120,
+            Tower::Overclocker => 80,
+            Tower::Amplifier => 90,
+            Tower::Generator => 100,
             Tower::Portal => 150,
         }
     }
@@ -102,28 +294,31 @@ impl Tower {
             Tower::Tesla => "icon_tesla",
             Tower::Water => "icon_water_bucket",
             Tower::Flame => "icon_flame",
+            // Tinted by `sprite_tint` to tell it apart from the piston
+            Tower::Mortar => "icon_piston",
+            // Support towers borrow other towers' art, tinted by `sprite_tint`
+            Tower::Overclocker => "icon_fan",
+            Tower::Amplifier => "icon_ice",
+            Tower::Generator => "icon_tesla",
             Tower::Portal => "icon_portal",
         }
     }
 
-    pub fn has_trigger_zone(&self) -> bool {
+    pub fn area(&self) -> TowerArea {
         match self {
-            Tower::Fan => false,
-            _ => true,
+            Tower::Oil | Tower::Acid | Tower::Water => TowerArea::ColumnBelow,
+            Tower::Fan => TowerArea::LineToWall,
+            Tower::TrapDoor => TowerArea::Box(Vec2::new(10.0, 10.0)),
+            Tower::Mortar => TowerArea::Box(Vec2::new(49.0, 29.0)),
+            Tower::Overclocker | Tower::Amplifier | Tower::Generator => TowerArea::None,
+            _ => TowerArea::Box(CELL_AREA),
         }
     }
 
-    pub fn gravity_influences_trigger(&self) -> bool {
-        match self {
-            Tower::Oil | Tower::Acid | Tower::Water => true,
-            _ => false,
-        }
-    }
-
-    pub fn custom_trigger_zone(&self) -> Option<Vec2> {
-        match self {
-            Tower::TrapDoor => Some(Vec2::new(10.0, 10.0)),
-            _ => None,
+    pub fn has_trigger_zone(&self) -> bool {
+        match self.area() {
+            TowerArea::Box(_) | TowerArea::ColumnBelow => true,
+            TowerArea::LineToWall | TowerArea::None => false,
         }
     }
 
@@ -163,6 +358,22 @@ impl Tower {
                 AttackSpecification::Damage(DamageType::Burning, 10),
                 AttackSpecification::Status(StatusEnum::Burned),
             ]),
+            Tower::Mortar => TowerAttackType::Projectile(ProjectileSpec {
+                speed: 40.,
+                gravity: 1.,
+                pierce: 0,
+                bounces: 0,
+                aim: ProjectileAim::NearestEnemy,
+                payload: vec![AttackSpecification::Explosion {
+                    radius: 12.,
+                    falloff: 0.6,
+                    payload: vec![
+                        AttackSpecification::Damage(DamageType::Physical, 25),
+                        AttackSpecification::Push(60.),
+                    ],
+                }],
+            }),
+            Tower::Overclocker | Tower::Amplifier | Tower::Generator => TowerAttackType::Passive,
             Tower::Portal => todo!(),
         }
     }
@@ -179,22 +390,31 @@ impl Tower {
             Tower::Tesla => 0.67,
             Tower::Water => 5.0,
             Tower::Flame => 0.67,
+            Tower::Mortar => 3.0,
+            Tower::Overclocker | Tower::Amplifier | Tower::Generator => 0.,
             Tower::Portal => 3.0,
         }
     }
 
-    pub fn requires_adjecent_wall(&self) -> bool {
+    pub fn placement_rules(&self) -> Vec<PlacementRule> {
         match self {
-            Tower::TrapDoor => true,
-            _ => false,
+            Tower::TrapDoor => vec![
+                PlacementRule::Surfaces(&[ExactPosition::Floor]),
+                PlacementRule::NotBottomRow,
+                PlacementRule::ExclusiveCell,
+            ],
+            _ => vec![],
         }
     }
 
-    pub fn requires_floor_placement(&self) -> bool {
-        match self {
-            Tower::TrapDoor => true,
-            _ => false,
-        }
+    /// How many of this tower a level allows, if it limits them
+    pub fn max_per_level(&self) -> Option<usize> {
+        self.placement_rules()
+            .into_iter()
+            .find_map(|rule| match rule {
+                PlacementRule::MaxPerLevel(max) => Some(max),
+                _ => None,
+            })
     }
 
     pub fn fire_sfx(&self) -> Option<Arc<dyn SoundFn>> {
@@ -203,6 +423,7 @@ impl Tower {
             Tower::Tesla => Some(Arc::new(SoundEffects::tesla_fire)),
             Tower::Piston => Some(Arc::new(SoundEffects::piston_fire)),
             Tower::Oil => Some(Arc::new(SoundEffects::oil_fire)),
+            Tower::Mortar => Some(Arc::new(SoundEffects::piston_fire)),
             Tower::Water => Some(Arc::new(SoundEffects::water_fire)),
             _ => None,
         }
@@ -1,11 +1,12 @@
 use crate::{
     assets::game_assets::HEALTH_BAR_WIDTH, data::{
-        get_collision, projectiles::DamageType, stats::{DamageMultiplier, DamageMultiplierAll, Stat}, PlayerState, StatusEffect,
+        get_collision, projectiles::{AttackSpecification, DamageType}, stats::{Armor, DamageMultiplier, DamageMultiplierAll, Stat}, PlayerState, StatusEffect,
         Tower,
         TowerCollision,
     },
     demo::enemy_movement::MovementDirection,
     gameplay::shared_systems::Lifetime,
+    screens::Screen,
     AppSystems,
     PausableSystems,
 };
@@ -13,21 +14,31 @@ use avian2d::prelude::{Collider, CollisionLayers, OnCollisionEnd, OnCollisionSta
 use bevy::ecs::relationship::DescendantIter;
 use bevy::prelude::*;
 use bevy_turborand::{DelegatedRng, GlobalRng};
+use std::collections::HashMap;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         Update,
         (
             active_tower_collision,
-            update_health_bars,
+            (tick_invulnerability, regenerate_shields),
+            (
+                try_enemy_damage,
+                do_enemy_damage,
+                update_health_bars,
+                tally_damage,
+            )
+                .chain(),
             (kill_at_0_health, do_kill_enemies).chain(),
-            (try_enemy_damage, do_enemy_damage).chain(),
         )
             .in_set(PausableSystems)
             .in_set(AppSystems::Update),
     );
+    app.add_systems(OnEnter(Screen::Gameplay), reset_damage_statistics);
+    app.init_resource::<DamageStatistics>();
     app.add_event::<KillEnemy>()
         .add_event::<DoDamageToEnemy>()
+        .add_event::<DamageOutcome>()
         .add_event::<BountyEarned>()
         .add_event::<TryDamageToEnemy>();
     app.add_observer(start_collision_damage_event);
@@ -48,6 +59,39 @@ pub struct Bounty(pub i32);
 /// Gives money when the entity is killed
 pub struct BountyEarned(pub Entity, pub i32);
 
+#[derive(Component, Clone, Copy, PartialEq, Reflect)]
+/// How many lives the player loses when the entity reaches the goal
+pub struct LivesPenalty(pub i32);
+
+impl Default for LivesPenalty {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+/// Attacks set off where the enemy dies. Only explosions do anything here.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+pub struct DeathEffect(pub Vec<AttackSpecification>);
+
+/// A layer of hit points that absorbs damage before health does. It starts recharging once the
+/// enemy has gone `regen_delay` seconds without being hit.
+#[derive(Component, Clone, PartialEq, Reflect)]
+pub struct Shield {
+    pub max: isize,
+    pub current: isize,
+    pub regen_per_second: f32,
+    regen_delay: Timer,
+    regen_progress: f32,
+}
+
+/// Per-source invulnerability windows, so the same tower can't hit an enemy many times at once
+#[derive(Component, Default, Clone, PartialEq, Reflect)]
+pub struct Invulnerability {
+    windows: HashMap<Entity, Timer>,
+}
+
+pub const INVULNERABILITY_WINDOW: f32 = 0.25;
+
 #[derive(Component, Default, Clone, Copy, PartialEq, Reflect)]
 pub struct EnemyChild;
 
@@ -65,6 +109,8 @@ pub struct TryDamageToEnemy {
     pub strength: usize,
     pub damage_type: DamageType,
     pub enemy: Entity,
+    /// Where the damage came from. Damage without a source ignores invulnerability windows.
+    pub source: Option<Entity>,
 }
 
 #[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
@@ -74,6 +120,37 @@ pub struct DoDamageToEnemy {
     pub enemy: Entity,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum DamageOutcomeKind {
+    /// Stopped by an invulnerability window or soaked up entirely by armor
+    Blocked,
+    /// Taken entirely by the shield
+    Absorbed,
+    Applied,
+    /// The killing blow, with damage to spare
+    Overkill,
+}
+
+/// The result of a damage attempt after it has gone through the whole pipeline
+#[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct DamageOutcome {
+    pub enemy: Entity,
+    pub damage_type: DamageType,
+    pub kind: DamageOutcomeKind,
+    pub absorbed: isize,
+    pub applied: isize,
+    pub overkill: isize,
+}
+
+/// Running totals of every damage outcome in the current level
+#[derive(Resource, Default, Debug, Clone, PartialEq, Reflect)]
+pub struct DamageStatistics {
+    pub applied: HashMap<DamageType, isize>,
+    pub absorbed: isize,
+    pub overkill: isize,
+    pub blocked: usize,
+}
+
 #[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
 pub struct KillEnemy(pub Entity);
 
@@ -108,7 +185,7 @@ pub fn do_kill_enemies(
 }
 
 pub fn update_health_bars(
-    mut events: EventReader<DoDamageToEnemy>,
+    mut events: EventReader<DamageOutcome>,
     mut health_bars: Query<&mut Transform, With<EnemyHealthBar>>,
     children_query: Query<&Children>,
     enemies: Query<&EnemyHealth>,
@@ -120,7 +197,9 @@ pub fn update_health_bars(
             println!("unable to find health bar for enemy {:?}", event.enemy);
             continue;
         };
-        let enemy = enemies.get(event.enemy).unwrap();
+        let Ok(enemy) = enemies.get(event.enemy) else {
+            continue;
+        };
         let mut health_bar_transform = health_bars.get_mut(health_bar_entity).unwrap();
 
         health_bar_transform.scale.x = (enemy.current as f32) / (enemy.max as f32);
@@ -147,6 +226,7 @@ fn start_collision_damage_event(
                 damage_type: DamageType::Physical,
                 enemy: enemy_target,
                 strength: 1,
+                source: Some(trigger.target()),
             });
 
             // Add a collision entity that deals damage on a timer while collision is active
@@ -175,15 +255,45 @@ fn active_tower_collision(
                 damage_type: DamageType::Physical,
                 enemy: entity,
                 strength: 1,
+                source: None,
             });
         }
     }
 }
 
-// An ailment is triggered on a timer
+fn tick_invulnerability(time: Res<Time>, mut enemies: Query<&mut Invulnerability>) {
+    for mut invulnerability in enemies.iter_mut() {
+        invulnerability.windows.retain(|_, timer| {
+            timer.tick(time.delta());
+            !timer.finished()
+        });
+    }
+}
+
+fn regenerate_shields(time: Res<Time>, mut shields: Query<&mut Shield>) {
+    for mut shield in shields.iter_mut() {
+        shield.regen_delay.tick(time.delta());
+        if !shield.regen_delay.finished() || shield.current >= shield.max {
+            shield.regen_progress = 0.;
+            continue;
+        }
+        shield.regen_progress += shield.regen_per_second * time.delta_secs();
+        let whole = shield.regen_progress.floor();
+        shield.regen_progress -= whole;
+        shield.current = (shield.current + whole as isize).min(shield.max);
+    }
+}
+
+/// Variance and type multipliers scale the hit, then armor takes a flat amount off the top
+pub fn mitigate_damage(raw: f32, multiplier: f32, armor: f32) -> isize {
+    ((raw * multiplier) - armor).max(0.) as isize
+}
+
 pub fn try_enemy_damage(
     mut attempts: EventReader<TryDamageToEnemy>,
     mut successes: EventWriter<DoDamageToEnemy>,
+    mut outcomes: EventWriter<DamageOutcome>,
+    mut enemies: Query<(&EnemyHealth, Option<&Stat<Armor>>, Option<&mut Invulnerability>)>,
     stats: Query<(
         &Stat<DamageMultiplierAll>,
         &Stat<DamageMultiplier<{ DamageType::Physical }>>,
@@ -195,22 +305,57 @@ pub fn try_enemy_damage(
     mut rng: ResMut<GlobalRng>,
 ) {
     for event in attempts.read() {
-        let mut damage =
+        let blocked = DamageOutcome {
+            enemy: event.enemy,
+            damage_type: event.damage_type,
+            kind: DamageOutcomeKind::Blocked,
+            absorbed: 0,
+            applied: 0,
+            overkill: 0,
+        };
+        let Ok((health, armor, invulnerability)) = enemies.get_mut(event.enemy) else {
+            continue;
+        };
+        // Dead enemies linger until they're despawned, hits on them don't count
+        if health.current <= 0 {
+            continue;
+        }
+
+        if let (Some(source), Some(mut invulnerability)) = (event.source, invulnerability) {
+            if invulnerability.windows.contains_key(&source) {
+                outcomes.write(blocked);
+                continue;
+            }
+            invulnerability.windows.insert(
+                source,
+                Timer::from_seconds(INVULNERABILITY_WINDOW, TimerMode::Once),
+            );
+        }
+
+        let raw =
             (rng.f32_normalized() * (event.damage as f32) * DAMAGE_VARIANCE) + event.damage as f32;
-        if let Ok((all, phys, burn, cold, chem, light)) = stats.get(event.enemy) {
-            let type_mul = match event.damage_type {
-                DamageType::Physical => phys.current_value(),
-                DamageType::Burning => burn.current_value(),
-                DamageType::Cold => cold.current_value(),
-                DamageType::Lightning => light.current_value(),
-                DamageType::Chemical => chem.current_value(),
-            };
-            damage = damage * all.current_value() * type_mul;
+        let multiplier = stats
+            .get(event.enemy)
+            .map(|(all, phys, burn, cold, chem, light)| {
+                let type_mul = match event.damage_type {
+                    DamageType::Physical => phys.current_value(),
+                    DamageType::Burning => burn.current_value(),
+                    DamageType::Cold => cold.current_value(),
+                    DamageType::Lightning => light.current_value(),
+                    DamageType::Chemical => chem.current_value(),
+                };
+                all.current_value() * type_mul
+            })
+            .unwrap_or(1.);
+        let armor = armor.map(|a| a.current_value()).unwrap_or(0.);
+
+        let damage = mitigate_damage(raw, multiplier, armor);
+        if damage <= 0 {
+            outcomes.write(blocked);
+            continue;
         }
-        // TODO: I-Frame logic, which is how damage can fail
-        // TODO: Elemental resistances and weaknesses from current status effects
         successes.write(DoDamageToEnemy {
-            damage: damage as isize,
+            damage,
             damage_type: event.damage_type,
             enemy: event.enemy,
         });
@@ -219,21 +364,69 @@ pub fn try_enemy_damage(
 
 pub fn do_enemy_damage(
     mut events: EventReader<DoDamageToEnemy>,
-    mut enemies: Query<&mut EnemyHealth>,
+    mut outcomes: EventWriter<DamageOutcome>,
+    mut enemies: Query<(&mut EnemyHealth, Option<&mut Shield>)>,
 ) {
     for event in events.read() {
-        if let Ok(mut health) = enemies.get_mut(event.enemy) {
-            health.current -= event.damage;
-            health.current = health.current.clamp(0, 1000);
+        let Ok((mut health, shield)) = enemies.get_mut(event.enemy) else {
+            warn!(target=?event.enemy, "Enemy target not found");
+            continue;
+        };
+        // An earlier hit this frame may have killed it already
+        if health.current <= 0 {
+            continue;
+        }
+
+        let mut remaining = event.damage;
+        let mut absorbed = 0;
+        if let Some(mut shield) = shield {
+            absorbed = remaining.min(shield.current);
+            shield.current -= absorbed;
+            remaining -= absorbed;
+            shield.regen_delay.reset();
+        }
 
-            debug!("Enemy {:?} has {} health", event.enemy, health.current);
+        let applied = remaining.min(health.current);
+        let overkill = remaining - applied;
+        health.current -= applied;
+        health.current = health.current.clamp(0, health.max);
+
+        let kind = if remaining == 0 {
+            DamageOutcomeKind::Absorbed
+        } else if health.current == 0 && overkill > 0 {
+            DamageOutcomeKind::Overkill
         } else {
-            warn!(target=?event.enemy, "Enemy target not found");
-            return;
+            DamageOutcomeKind::Applied
         };
+        outcomes.write(DamageOutcome {
+            enemy: event.enemy,
+            damage_type: event.damage_type,
+            kind,
+            absorbed,
+            applied,
+            overkill,
+        });
+
+        debug!("Enemy {:?} has {} health", event.enemy, health.current);
+    }
+}
+
+fn tally_damage(mut events: EventReader<DamageOutcome>, mut stats: ResMut<DamageStatistics>) {
+    for outcome in events.read() {
+        if outcome.kind == DamageOutcomeKind::Blocked {
+            stats.blocked += 1;
+            continue;
+        }
+        *stats.applied.entry(outcome.damage_type).or_default() += outcome.applied;
+        stats.absorbed += outcome.absorbed;
+        stats.overkill += outcome.overkill;
     }
 }
 
+fn reset_damage_statistics(mut stats: ResMut<DamageStatistics>) {
+    *stats = DamageStatistics::default();
+}
+
 impl EnemyHealth {
     pub fn new(health: isize) -> Self {
         Self {
@@ -241,4 +434,31 @@ impl EnemyHealth {
             current: health,
         }
     }
+
+    pub fn current(&self) -> isize {
+        self.current
+    }
+
+    pub fn max(&self) -> isize {
+        self.max
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.max <= 0 {
+            return 0.;
+        }
+        self.current as f32 / self.max as f32
+    }
+}
+
+impl Shield {
+    pub fn new(max: isize, regen_per_second: f32, regen_delay: f32) -> Self {
+        Self {
+            max,
+            current: max,
+            regen_per_second,
+            regen_delay: Timer::from_seconds(regen_delay, TimerMode::Once),
+            regen_progress: 0.,
+        }
+    }
 }
@@ -3,18 +3,28 @@ use bevy::{math::NormedVectorSpace, prelude::*};
 
 use crate::{
     AppSystems, PausableSystems,
-    data::stats::{MoveSpeed, Stat},
-    gameplay::{animation::AnimationFrameQueue, stats::StatSet},
+    data::{
+        stats::{MoveSpeed, Stat},
+        status_effects::{Confused, KnockedBack, StatusEffect, StatusEnum},
+    },
+    gameplay::{
+        animation::AnimationFrameQueue, stats::StatSet, status_effects::common::TryRemoveStatus,
+    },
     level::{components::PathNode, resource::CellDirection},
+    prefabs::physics::GamePhysicsLayer as GPL,
     screens::Screen,
 };
 
 use super::enemy_health::EnemyHealth;
 
+/// How long an enemy is knocked back before touching the level can end it, so it has time to
+/// leave the ground it was hit on
+const KNOCKBACK_LIFTOFF_SECS: f32 = 0.15;
+
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         Update,
-        (flip_sprite, follow_path, enemy_movement)
+        (flip_sprite, land_knocked_back, follow_path, enemy_movement)
             .chain()
             .run_if(in_state(Screen::Gameplay))
             .in_set(AppSystems::Update)
@@ -38,6 +48,32 @@ pub struct EnemyControllerBundle {
     movement: Stat<MoveSpeed>,
 }
 
+/// Knocked back enemies get their footing back once they touch the level again. The status's
+/// own timer only ends it for enemies that never land.
+fn land_knocked_back(
+    enemies: Query<(Entity, &StatusEffect<KnockedBack>)>,
+    collisions: Collisions,
+    layers: Query<&CollisionLayers>,
+    mut removals: EventWriter<TryRemoveStatus>,
+) {
+    for (enemy, knocked_back) in enemies.iter() {
+        if knocked_back.duration.elapsed_secs() < KNOCKBACK_LIFTOFF_SECS {
+            continue;
+        }
+        let landed = collisions.entities_colliding_with(enemy).any(|other| {
+            layers
+                .get(other)
+                .is_ok_and(|layers| layers.memberships.has_all(GPL::Level))
+        });
+        if landed {
+            removals.write(TryRemoveStatus {
+                status: StatusEnum::KnockedBack,
+                enemy,
+            });
+        }
+    }
+}
+
 /// Sends [`MovementAction`] events based on enemy's waypoint direction
 fn follow_path(
     mut enemies: Query<
@@ -46,13 +82,30 @@ fn follow_path(
             &mut MovementDirection,
             &mut GravityScale,
             &Stat<MoveSpeed>,
+            Has<StatusEffect<Confused>>,
+            Has<StatusEffect<KnockedBack>>,
         ),
         With<EnemyHealth>,
     >,
     nodes: Query<(&Transform, &PathNode)>,
 ) {
     // path instructions to walk around in a circle
-    for (enemy_transform, mut movement_direction, mut gravity_scale, speed) in enemies.iter_mut() {
+    for (
+        enemy_transform,
+        mut movement_direction,
+        mut gravity_scale,
+        speed,
+        confused,
+        knocked_back,
+    ) in enemies.iter_mut()
+    {
+        // Enemies that have been sent flying don't steer until they land
+        if knocked_back {
+            movement_direction.0 = Vec2::ZERO;
+            gravity_scale.0 = 1.0;
+            continue;
+        }
+
         let pos = enemy_transform.translation.xy();
 
         let mut nodes_sorted_by_distance = nodes
@@ -84,6 +137,11 @@ fn follow_path(
                 CellDirection::Left | CellDirection::Right => average,
             },
         };
+
+        // Confused enemies walk the path backwards
+        if confused {
+            movement_direction.0 = -movement_direction.0;
+        }
     }
 }
 
@@ -0,0 +1,169 @@
+use crate::data::blueprints::{Blueprint, BlueprintTower};
+use crate::data::levels::LevelData;
+use crate::data::placement::{PlacementError, can_place};
+use crate::data::{PlayerState, Tower};
+use crate::gameplay::messages::DisplayFlashMessage;
+use crate::gameplay::tower_placement::{TowerPlacementEvent, placement_world};
+use crate::level::components::Adjacent;
+use crate::level::resource::{CurrentLoadedLevel, UnlockedTowers};
+use crate::prelude::*;
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+
+const EXPORT_KEY: KeyCode = KeyCode::F5;
+const IMPORT_KEY: KeyCode = KeyCode::F9;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            export_blueprint.run_if(input_just_pressed(EXPORT_KEY)),
+            import_blueprint.run_if(input_just_pressed(IMPORT_KEY)),
+        )
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn blueprint_path(level: usize) -> std::path::PathBuf {
+    std::path::PathBuf::from("blueprints").join(format!("level_{}.txt", level + 1))
+}
+
+/// Saves every placed tower to a text file, and logs it so it can be copied from the console
+fn export_blueprint(
+    level: Res<CurrentLoadedLevel>,
+    towers: Query<(&ChildOf, &Tower)>,
+    panels: Query<&Adjacent>,
+    mut commands: Commands,
+) {
+    let mut blueprint = Blueprint {
+        level: level.0,
+        towers: towers
+            .iter()
+            .filter_map(|(parent, tower)| {
+                panels.get(parent.0).ok().map(|face| BlueprintTower {
+                    tower: *tower,
+                    face: *face,
+                    tier: 1,
+                })
+            })
+            .collect(),
+    };
+    // Keep the file stable between exports of the same layout
+    blueprint
+        .towers
+        .sort_by_key(|entry| (entry.face.id.unit_x, entry.face.id.unit_y));
+    let text = blueprint.to_string();
+    info!("Blueprint for level {}:\n{text}", level.0 + 1);
+
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let path = blueprint_path(level.0);
+        let saved = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::write(&path, &text));
+        if let Err(e) = saved {
+            warn!("Couldn't save blueprint to {}: {e}", path.display());
+            commands.trigger(DisplayFlashMessage::new("Couldn't save blueprint"));
+            return;
+        }
+    }
+    commands.trigger(DisplayFlashMessage::new(format!(
+        "Saved blueprint with {} towers",
+        blueprint.towers.len()
+    )));
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn read_blueprint(level: usize) -> Result<String, String> {
+    let path = blueprint_path(level);
+    std::fs::read_to_string(&path).map_err(|e| format!("Couldn't read {}: {e}", path.display()))
+}
+
+#[cfg(target_family = "wasm")]
+fn read_blueprint(_level: usize) -> Result<String, String> {
+    Err("Blueprints can't be loaded in the browser".to_string())
+}
+
+/// Places towers from this level's saved blueprint, skipping anything the player can't afford or
+/// that doesn't fit, and reporting faces that no longer exist on the map
+fn import_blueprint(
+    level: Res<CurrentLoadedLevel>,
+    level_data: Res<LevelData>,
+    unlocked: Res<UnlockedTowers>,
+    mut player_state: ResMut<PlayerState>,
+    towers: Query<(&ChildOf, &Tower)>,
+    panels: Query<(Entity, &Adjacent)>,
+    adjacent_placements: Query<&Adjacent>,
+    mut placements: EventWriter<TowerPlacementEvent>,
+    mut commands: Commands,
+) {
+    let blueprint = match read_blueprint(level.0)
+        .and_then(|text| Blueprint::parse(&text, level.0).map_err(|e| e.to_string()))
+    {
+        Ok(blueprint) => blueprint,
+        Err(reason) => {
+            warn!("{reason}");
+            commands.trigger(DisplayFlashMessage::new(reason));
+            return;
+        }
+    };
+
+    // Towers placed earlier in the blueprint count against the ones after them
+    let mut world = placement_world(
+        &player_state,
+        level_data.tower_limits(level.0),
+        &towers,
+        &adjacent_placements,
+    );
+    let (mut placed, mut unaffordable, mut skipped, mut missing) = (0, 0, 0, 0);
+    for entry in blueprint.towers {
+        let Some((panel, face)) = panels.iter().find(|(_, face)| **face == entry.face) else {
+            warn!("Blueprint face {:?} no longer exists", entry.face);
+            missing += 1;
+            continue;
+        };
+        if !unlocked.contains(entry.tower) {
+            info!("Skipped {:?}, it hasn't been unlocked", entry.tower);
+            skipped += 1;
+            continue;
+        }
+        match can_place(entry.tower, face, &world) {
+            Ok(()) => {}
+            Err(PlacementError::InsufficientFunds) => {
+                unaffordable += 1;
+                continue;
+            }
+            // Already built towers aren't worth mentioning
+            Err(_) if world.towers.contains(&(entry.tower, *face)) => continue,
+            Err(reason) => {
+                info!("Skipped {:?} at {:?}: {reason}", entry.tower, entry.face);
+                skipped += 1;
+                continue;
+            }
+        }
+
+        world.money -= entry.tower.price();
+        world.towers.push((entry.tower, *face));
+        placements.write(TowerPlacementEvent::Accepted(
+            entry.tower,
+            panel,
+            face.face(),
+        ));
+        placed += 1;
+    }
+    player_state.money = world.money;
+
+    let mut summary = format!("Placed {placed} towers from blueprint");
+    if unaffordable > 0 {
+        summary += &format!(", {unaffordable} unaffordable");
+    }
+    if skipped > 0 {
+        summary += &format!(", {skipped} skipped");
+    }
+    if missing > 0 {
+        summary += &format!(", {missing} no longer fit the map");
+    }
+    commands.trigger(DisplayFlashMessage::new(summary));
+}
@@ -0,0 +1,127 @@
+use crate::{
+    data::{
+        stats::{MoveSpeed, Stat},
+        status_effects::{StatusEffect, StatusEffectTrait, StatusEnum},
+    },
+    demo::enemy_health::EnemyHealth,
+    gameplay::{
+        messages::DisplayFlashMessage, stats::StatSet, status_effects::common::RemoveStatus,
+    },
+    prelude::*,
+};
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Boss>().register_type::<BossPhase>();
+
+    app.add_systems(
+        Update,
+        (
+            advance_boss_phases.before(StatSet::Modify),
+            boss_phase_speed.in_set(StatSet::Modify),
+        )
+            .in_set(PausableSystems)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// A single stage of a boss fight, active once the boss' health fraction drops to `threshold`
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct BossPhase {
+    pub threshold: f32,
+    pub speed_multiplier: f32,
+    pub immunities: Vec<StatusEnum>,
+}
+
+/// Marks an enemy as a boss. Bosses get a bar in the HUD and move through their phases as they
+/// take damage. Phases are expected to be ordered by descending threshold.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+pub struct Boss {
+    phases: Vec<BossPhase>,
+    current_phase: usize,
+}
+
+impl BossPhase {
+    pub fn new(threshold: f32, speed_multiplier: f32, immunities: &[StatusEnum]) -> Self {
+        Self {
+            threshold,
+            speed_multiplier,
+            immunities: immunities.to_vec(),
+        }
+    }
+}
+
+impl Boss {
+    pub fn new(phases: Vec<BossPhase>) -> Self {
+        Self {
+            phases,
+            current_phase: 0,
+        }
+    }
+
+    pub fn phase(&self) -> Option<&BossPhase> {
+        self.phases.get(self.current_phase)
+    }
+
+    pub fn phase_index(&self) -> usize {
+        self.current_phase
+    }
+
+    pub fn phase_count(&self) -> usize {
+        self.phases.len()
+    }
+
+    pub fn is_immune(&self, status: StatusEnum) -> bool {
+        self.phase()
+            .is_some_and(|phase| phase.immunities.contains(&status))
+    }
+
+    /// The deepest phase reached at the given health fraction. Bosses never fall back to an
+    /// earlier phase, even if they heal.
+    fn phase_for(&self, health_fraction: f32) -> usize {
+        self.phases
+            .iter()
+            .rposition(|phase| health_fraction <= phase.threshold)
+            .unwrap_or(0)
+            .max(self.current_phase)
+    }
+}
+
+pub fn advance_boss_phases(
+    mut bosses: Query<(&mut Boss, &EnemyHealth, Option<&Name>)>,
+    mut commands: Commands,
+) {
+    for (mut boss, health, name) in bosses.iter_mut() {
+        let next_phase = boss.phase_for(health.fraction());
+        if next_phase == boss.current_phase {
+            continue;
+        }
+        boss.current_phase = next_phase;
+
+        let name = name.map(|n| n.as_str()).unwrap_or("The boss");
+        commands.trigger(DisplayFlashMessage::new(format!(
+            "{name} enters phase {}!",
+            next_phase + 1
+        )));
+    }
+}
+
+pub fn boss_phase_speed(mut bosses: Query<(&Boss, &mut Stat<MoveSpeed>)>) {
+    for (boss, mut speed) in bosses.iter_mut() {
+        if let Some(phase) = boss.phase() {
+            speed.multiplier(phase.speed_multiplier);
+        }
+    }
+}
+
+/// Strips statuses that were applied before a boss became immune to them
+pub fn cleanse_boss_immunities<T: StatusEffectTrait>(
+    bosses: Query<(Entity, &Boss, &StatusEffect<T>)>,
+    mut events: EventWriter<RemoveStatus<T>>,
+) {
+    for (enemy, boss, status) in bosses.iter() {
+        if boss.is_immune(T::corresponding_enum()) {
+            events.write(RemoveStatus::new(enemy, status.strength));
+        }
+    }
+}
@@ -1,15 +1,21 @@
 use crate::{
-    demo::enemy_health::{DoDamageToEnemy, EnemyHealth},
+    demo::enemy_health::{DamageOutcome, DamageOutcomeKind, EnemyHealth},
+    gameplay::status_effects::common::StatusResisted,
     prelude::*,
     theme::prelude::*,
 };
-use bevy::prelude::*;
+use bevy::{color::palettes::tailwind, prelude::*};
 use bevy_turborand::{DelegatedRng, GlobalRng};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         Update,
-        (show_damage_numbers, animate_damage_numbers).run_if(in_state(Screen::Gameplay)),
+        (
+            show_damage_numbers,
+            show_resisted_statuses,
+            animate_damage_numbers,
+        )
+            .run_if(in_state(Screen::Gameplay)),
     );
 }
 
@@ -24,7 +30,7 @@ struct DamageNumberLifetime {
 
 fn show_damage_numbers(
     enemies: Query<&Transform, With<EnemyHealth>>,
-    mut events: EventReader<DoDamageToEnemy>,
+    mut events: EventReader<DamageOutcome>,
     mut rng: ResMut<GlobalRng>,
     mut commands: Commands,
 ) {
@@ -36,11 +42,26 @@ fn show_damage_numbers(
         translation.x += (rng.f32() - 0.5) * 3.0;
         translation.y += (rng.f32() - 0.5) * 2.0;
 
+        let (text, color, size) = match event.kind {
+            DamageOutcomeKind::Blocked => ("Blocked".to_string(), tailwind::GRAY_400.into(), 12.0),
+            DamageOutcomeKind::Absorbed => {
+                (event.absorbed.to_string(), tailwind::SKY_300.into(), 16.0)
+            }
+            DamageOutcomeKind::Applied => {
+                (event.applied.to_string(), event.damage_type.color(), 18.0)
+            }
+            DamageOutcomeKind::Overkill => (
+                format!("{}!", event.applied + event.overkill),
+                event.damage_type.color(),
+                24.0,
+            ),
+        };
+
         commands.spawn((
             StateScoped(Screen::Gameplay),
-            Text2d::new(event.damage.to_string()),
-            TextColor(event.damage_type.color()),
-            TextFont::from_font_size(18.0).with_font(TITLE_FONT),
+            Text2d::new(text),
+            TextColor(color),
+            TextFont::from_font_size(size).with_font(TITLE_FONT),
             DamageNumber,
             DamageNumberLifetime {
                 timer: Timer::from_seconds(0.6, TimerMode::Once),
@@ -51,6 +72,36 @@ fn show_damage_numbers(
     }
 }
 
+fn show_resisted_statuses(
+    enemies: Query<&Transform, With<EnemyHealth>>,
+    mut events: EventReader<StatusResisted>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        let Ok(transform) = enemies.get(event.enemy) else {
+            continue;
+        };
+        let translation = transform.translation + Vec3::Y * 3.0;
+
+        commands.spawn((
+            StateScoped(Screen::Gameplay),
+            Text2d::new(if event.partial {
+                format!("{} reduced", event.status.name())
+            } else {
+                format!("Resisted {}", event.status.name())
+            }),
+            TextColor(event.status.color()),
+            TextFont::from_font_size(12.0).with_font(TITLE_FONT),
+            DamageNumber,
+            DamageNumberLifetime {
+                timer: Timer::from_seconds(0.8, TimerMode::Once),
+                velocity: Vec3::Y * 0.6,
+            },
+            Transform::from_translation(translation).with_scale(Vec3::splat(0.1)),
+        ));
+    }
+}
+
 fn animate_damage_numbers(
     mut commands: Commands,
     time: Res<Time>,
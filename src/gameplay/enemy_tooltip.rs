@@ -0,0 +1,225 @@
+use crate::data::enemies::EnemyArchetype;
+use crate::data::projectiles::DamageType;
+use crate::data::stats::{Armor, DamageMultiplier, DamageMultiplierAll, Stat};
+use crate::data::status_effects::{Resistance, StatusResistances};
+use crate::demo::enemy_health::{EnemyHealth, Shield};
+use crate::gameplay::status_effects::common::ActiveStatuses;
+use crate::prefabs::enemies::EnemySprite;
+use crate::prelude::*;
+use crate::theme::widget;
+use bevy::color::palettes::tailwind;
+use bevy::prelude::*;
+
+/// Where the tooltip sits relative to the enemy on screen, so it doesn't cover it
+const TOOLTIP_OFFSET: Vec2 = Vec2::new(24.0, -24.0);
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<HoveredEnemy>();
+    app.add_systems(OnEnter(Screen::Gameplay), on_enter_game);
+    app.add_systems(OnExit(Screen::Gameplay), clear_hovered_enemy);
+    app.add_systems(
+        Update,
+        update_enemy_tooltip.run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_observer(hover_enemy);
+    app.add_observer(unhover_enemy);
+}
+
+/// The enemy whose sprite is under the cursor, if any
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HoveredEnemy(pub Option<Entity>);
+
+#[derive(Component)]
+struct EnemyTooltip;
+
+#[derive(Component)]
+enum EnemyTooltipText {
+    Name,
+    Details,
+}
+
+type DamageMultipliers = (
+    &'static Stat<DamageMultiplierAll>,
+    &'static Stat<DamageMultiplier<{ DamageType::Physical }>>,
+    &'static Stat<DamageMultiplier<{ DamageType::Burning }>>,
+    &'static Stat<DamageMultiplier<{ DamageType::Cold }>>,
+    &'static Stat<DamageMultiplier<{ DamageType::Lightning }>>,
+    &'static Stat<DamageMultiplier<{ DamageType::Chemical }>>,
+);
+
+fn on_enter_game(mut commands: Commands) {
+    commands.spawn((
+        StateScoped(Screen::Gameplay),
+        Name::new("Enemy Tooltip"),
+        EnemyTooltip,
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        GlobalZIndex(5),
+        BackgroundColor(Color::BLACK.with_alpha(0.85)),
+        BorderColor(tailwind::RED_300.into()),
+        BorderRadius::all(Val::Px(4.0)),
+        Node {
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        children![
+            (widget::label("ENEMY"), EnemyTooltipText::Name),
+            (widget::body_text(""), EnemyTooltipText::Details),
+        ],
+    ));
+}
+
+fn clear_hovered_enemy(mut hovered: ResMut<HoveredEnemy>) {
+    hovered.0 = None;
+}
+
+fn hover_enemy(
+    trigger: Trigger<Pointer<Over>>,
+    sprites: Query<&ChildOf, With<EnemySprite>>,
+    mut hovered: ResMut<HoveredEnemy>,
+) {
+    // Hovers bubble up through the hierarchy, so only react to the sprite itself
+    if trigger.target() != trigger.target {
+        return;
+    }
+    if let Ok(parent) = sprites.get(trigger.target) {
+        hovered.0 = Some(parent.0);
+    }
+}
+
+fn unhover_enemy(
+    trigger: Trigger<Pointer<Out>>,
+    sprites: Query<&ChildOf, With<EnemySprite>>,
+    mut hovered: ResMut<HoveredEnemy>,
+) {
+    if trigger.target() != trigger.target {
+        return;
+    }
+    if let Ok(parent) = sprites.get(trigger.target) {
+        if hovered.0 == Some(parent.0) {
+            hovered.0 = None;
+        }
+    }
+}
+
+fn update_enemy_tooltip(
+    hovered: Res<HoveredEnemy>,
+    enemies: Query<(
+        &EnemyHealth,
+        &ActiveStatuses,
+        &GlobalTransform,
+        Option<&EnemyArchetype>,
+        Option<&Name>,
+        Option<&Shield>,
+        Option<&Stat<Armor>>,
+        Option<&StatusResistances>,
+    )>,
+    multipliers: Query<DamageMultipliers>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut tooltips: Query<(&mut Visibility, &mut Node), With<EnemyTooltip>>,
+    mut texts: Query<(&mut Text, &EnemyTooltipText)>,
+) {
+    let Ok((mut visibility, mut node)) = tooltips.single_mut() else {
+        return;
+    };
+    let Some(enemy) = hovered.0 else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    let (Ok(enemy_info), Ok(multipliers), Ok((camera, camera_transform))) =
+        (enemies.get(enemy), multipliers.get(enemy), cameras.single())
+    else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    let (health, statuses, transform, archetype, name, shield, armor, resistances) = enemy_info;
+    let Ok(position) = camera.world_to_viewport(camera_transform, transform.translation()) else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    *visibility = Visibility::Inherited;
+    node.left = Val::Px(position.x + TOOLTIP_OFFSET.x);
+    node.top = Val::Px(position.y + TOOLTIP_OFFSET.y);
+
+    let title = archetype
+        .map(|archetype| archetype.name())
+        .or(name.map(|name| name.as_str()))
+        .unwrap_or("Enemy");
+
+    let mut lines = vec![format!("Health: {}/{}", health.current(), health.max())];
+    if let Some(shield) = shield {
+        lines.push(format!("Shield: {}/{}", shield.current, shield.max));
+    }
+    if let Some(armor) = armor.filter(|armor| armor.current_value() > 0.) {
+        lines.push(format!("Armor: {:.1}", armor.current_value()));
+    }
+
+    let statuses = statuses.sorted();
+    if statuses.is_empty() {
+        lines.push("No statuses".to_string());
+    }
+    for (status, active) in statuses {
+        lines.push(format!(
+            "{} {} - {:.1}s",
+            status.name(),
+            active.strength,
+            active.duration.remaining_secs()
+        ));
+    }
+
+    lines.push("Damage taken:".to_string());
+    lines.extend(damage_taken(multipliers));
+
+    if let Some(StatusResistances(resistances)) = resistances {
+        let names = |immune: bool| {
+            resistances
+                .iter()
+                .filter(|(_, resistance)| matches!(resistance, Resistance::Immune) == immune)
+                .map(|(status, _)| status.name())
+                .collect::<Vec<_>>()
+        };
+        let (immune, resisted) = (names(true), names(false));
+        if !immune.is_empty() {
+            lines.push(format!("Immune to {}", immune.join(", ")));
+        }
+        if !resisted.is_empty() {
+            lines.push(format!("Resists {}", resisted.join(", ")));
+        }
+    }
+
+    for (mut text, kind) in texts.iter_mut() {
+        text.0 = match kind {
+            EnemyTooltipText::Name => title.to_uppercase(),
+            EnemyTooltipText::Details => lines.join("\n"),
+        };
+    }
+}
+
+/// "Burning x0.25" for each damage type, with the global multiplier folded in
+fn damage_taken(
+    (all, physical, burning, cold, lightning, chemical): (
+        &Stat<DamageMultiplierAll>,
+        &Stat<DamageMultiplier<{ DamageType::Physical }>>,
+        &Stat<DamageMultiplier<{ DamageType::Burning }>>,
+        &Stat<DamageMultiplier<{ DamageType::Cold }>>,
+        &Stat<DamageMultiplier<{ DamageType::Lightning }>>,
+        &Stat<DamageMultiplier<{ DamageType::Chemical }>>,
+    ),
+) -> Vec<String> {
+    [
+        (DamageType::Physical, physical.current_value()),
+        (DamageType::Burning, burning.current_value()),
+        (DamageType::Cold, cold.current_value()),
+        (DamageType::Lightning, lightning.current_value()),
+        (DamageType::Chemical, chemical.current_value()),
+    ]
+    .into_iter()
+    .map(|(damage_type, multiplier)| {
+        format!("  {damage_type} x{:.2}", multiplier * all.current_value())
+    })
+    .collect()
+}
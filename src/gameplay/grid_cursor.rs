@@ -0,0 +1,200 @@
+use crate::data::PointerInteractionState;
+use crate::level::components::LEVEL_SCALING;
+use crate::level::resource::{CellDirection, Level};
+use crate::prelude::*;
+use bevy::color::palettes::css;
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use bevy::window::CursorMoved;
+
+const CONFIRM_KEY: KeyCode = KeyCode::Enter;
+const ROTATE_KEY: KeyCode = KeyCode::KeyR;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<GridCursor>();
+    app.add_event::<ConfirmPlacement>();
+    app.add_systems(
+        Update,
+        (
+            follow_mouse,
+            move_with_keys,
+            move_with_gamepad,
+            rotate_with_key.run_if(input_just_pressed(ROTATE_KEY)),
+            confirm_with_key.run_if(input_just_pressed(CONFIRM_KEY)),
+        )
+            .chain()
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_systems(
+        Update,
+        draw_grid_cursor.run_if(in_state(Screen::Gameplay).and(is_placing)),
+    );
+}
+
+/// The cell being pointed at, and which of its walls or floors. Moved by the mouse, arrow keys,
+/// WASD or a gamepad D-pad.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub struct GridCursor {
+    pub cell: IVec2,
+    pub face: CellDirection,
+}
+
+impl Default for GridCursor {
+    fn default() -> Self {
+        Self {
+            cell: IVec2::ZERO,
+            face: CellDirection::Down,
+        }
+    }
+}
+
+/// Sent when the player wants to place whatever's under the grid cursor
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub struct ConfirmPlacement;
+
+impl GridCursor {
+    /// Moves to `cell`, keeping the same face if the new cell has one there
+    fn move_to(&mut self, cell: IVec2, level: &Level) {
+        if !level.contains(cell) {
+            return;
+        }
+        self.cell = cell;
+        if !level.surfaces(cell).contains(&self.face) {
+            self.rotate(level);
+        }
+    }
+
+    /// Steps clockwise to the next face that can hold a tower
+    fn rotate(&mut self, level: &Level) {
+        let surfaces = level.surfaces(self.cell);
+        let mut face = self.face;
+        for _ in 0..4 {
+            face = face.clockwise();
+            if surfaces.contains(&face) {
+                self.face = face;
+                return;
+            }
+        }
+    }
+}
+
+pub fn is_placing(state: Res<State<PointerInteractionState>>) -> bool {
+    matches!(state.get(), PointerInteractionState::Placing(_))
+}
+
+fn follow_mouse(
+    mut moves: EventReader<CursorMoved>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    level: Res<Level>,
+    mut cursor: ResMut<GridCursor>,
+) {
+    let Some(moved) = moves.read().last() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = cameras.single() else {
+        return;
+    };
+    let Ok(position) = camera.viewport_to_world_2d(camera_transform, moved.position) else {
+        return;
+    };
+
+    let cell = (position / LEVEL_SCALING).round().as_ivec2();
+    if !level.contains(cell) {
+        return;
+    }
+    // Pick whichever mountable face is closest to the mouse within the cell
+    let offset = position - cell.as_vec2() * LEVEL_SCALING;
+    let closest = level.surfaces(cell).into_iter().min_by(|a, b| {
+        let to_a = offset.distance(a.vec() * LEVEL_SCALING / 2.);
+        let to_b = offset.distance(b.vec() * LEVEL_SCALING / 2.);
+        to_a.total_cmp(&to_b)
+    });
+
+    let mut next = *cursor;
+    next.cell = cell;
+    if let Some(face) = closest {
+        next.face = face;
+    }
+    // Only touch the resource when something changed, so change detection means something
+    cursor.set_if_neq(next);
+}
+
+fn move_with_keys(
+    input: Res<ButtonInput<KeyCode>>,
+    level: Res<Level>,
+    mut cursor: ResMut<GridCursor>,
+) {
+    let step = [
+        ([KeyCode::ArrowUp, KeyCode::KeyW], IVec2::Y),
+        ([KeyCode::ArrowDown, KeyCode::KeyS], IVec2::NEG_Y),
+        ([KeyCode::ArrowLeft, KeyCode::KeyA], IVec2::NEG_X),
+        ([KeyCode::ArrowRight, KeyCode::KeyD], IVec2::X),
+    ]
+    .into_iter()
+    .filter(|(keys, _)| input.any_just_pressed(*keys))
+    .map(|(_, step)| step)
+    .sum::<IVec2>();
+
+    if step != IVec2::ZERO {
+        let cell = cursor.cell + step;
+        cursor.move_to(cell, &level);
+    }
+}
+
+fn move_with_gamepad(
+    gamepads: Query<&Gamepad>,
+    level: Res<Level>,
+    mut cursor: ResMut<GridCursor>,
+    mut confirms: EventWriter<ConfirmPlacement>,
+    mut next_pointer_state: ResMut<NextState<PointerInteractionState>>,
+) {
+    for gamepad in gamepads.iter() {
+        let step = [
+            (GamepadButton::DPadUp, IVec2::Y),
+            (GamepadButton::DPadDown, IVec2::NEG_Y),
+            (GamepadButton::DPadLeft, IVec2::NEG_X),
+            (GamepadButton::DPadRight, IVec2::X),
+        ]
+        .into_iter()
+        .filter(|(button, _)| gamepad.just_pressed(*button))
+        .map(|(_, step)| step)
+        .sum::<IVec2>();
+
+        if step != IVec2::ZERO {
+            let cell = cursor.cell + step;
+            cursor.move_to(cell, &level);
+        }
+        if gamepad.any_just_pressed([GamepadButton::LeftTrigger, GamepadButton::RightTrigger]) {
+            cursor.rotate(&level);
+        }
+        if gamepad.just_pressed(GamepadButton::South) {
+            confirms.write(ConfirmPlacement);
+        }
+        if gamepad.just_pressed(GamepadButton::East) {
+            next_pointer_state.set(PointerInteractionState::Selecting);
+        }
+    }
+}
+
+fn rotate_with_key(level: Res<Level>, mut cursor: ResMut<GridCursor>) {
+    cursor.rotate(&level);
+}
+
+fn confirm_with_key(mut confirms: EventWriter<ConfirmPlacement>) {
+    confirms.write(ConfirmPlacement);
+}
+
+fn draw_grid_cursor(cursor: Res<GridCursor>, mut gizmos: Gizmos) {
+    let center = cursor.cell.as_vec2() * LEVEL_SCALING;
+    gizmos.rect_2d(
+        Isometry2d::from_translation(center),
+        Vec2::splat(LEVEL_SCALING),
+        css::WHITE.with_alpha(0.5),
+    );
+
+    // Underline the face the tower would be mounted on
+    let face = cursor.face.vec();
+    let along = Vec2::new(face.y, face.x) * LEVEL_SCALING / 2.;
+    let edge = center + face * LEVEL_SCALING / 2.;
+    gizmos.line_2d(edge - along, edge + along, css::YELLOW);
+}
@@ -1,15 +1,44 @@
+use crate::data::levels::LevelData;
+use crate::data::placement::max_per_level;
 use crate::gameplay::messages::DisplayFlashMessage;
+use crate::gameplay::towers::support::TowerCell;
+use crate::level::resource::{CurrentLoadedLevel, UnlockedTowers};
 use crate::theme::palette::LABEL_TEXT;
 use crate::{data::*, prelude::*, theme::prelude::*};
 use bevy::color::palettes::tailwind;
 use bevy::input::common_conditions::input_just_pressed;
 use bevy::{ecs::spawn::*, prelude::*};
 
+const NEXT_TAB_KEY: KeyCode = KeyCode::Tab;
+const SHORTCUT_KEYS: [KeyCode; 9] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+
 pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<SelectedElement>();
     app.add_systems(OnEnter(Screen::Gameplay), on_enter_game);
     app.add_systems(
         Update,
-        highlight_hovered_tile
+        (highlight_hovered_tile, update_stock)
+            .in_set(PausableSystems)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_systems(
+        Update,
+        (
+            next_tab.run_if(input_just_pressed(NEXT_TAB_KEY)),
+            select_with_shortcut,
+            show_selected_tab.run_if(resource_changed::<SelectedElement>),
+        )
+            .chain()
             .in_set(PausableSystems)
             .run_if(in_state(Screen::Gameplay)),
     );
@@ -25,18 +54,63 @@ pub(super) fn plugin(app: &mut App) {
 #[derive(Component, Debug, Reflect)]
 pub struct HotbarItem;
 
+/// The tab showing the towers of one element
+#[derive(Component, Debug, Reflect)]
+struct HotbarTab(TowerElement);
+
+/// Which element's towers the hotbar is showing
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+struct SelectedElement(TowerElement);
+
+impl Default for SelectedElement {
+    fn default() -> Self {
+        Self(TowerElement::Mechanical)
+    }
+}
+
+/// Shows how many more of a tower can be placed, for towers the level limits
+#[derive(Component, Debug, Reflect)]
+struct HotbarStock;
+
 #[derive(Component)]
 struct CancelInput;
 
-fn on_enter_game(mut commands: Commands, assets: Res<UiAssets>) {
-    let mut towers = Tower::all();
+/// Towers in the order they're shown in their element's tab, which is also the order of their
+/// number key shortcuts
+fn tab_towers(element: TowerElement) -> Vec<Tower> {
+    let mut towers: Vec<_> = Tower::all()
+        .into_iter()
+        .filter(|tower| tower.element() == element)
+        .collect();
     towers.sort_by(|a, b| a.price().cmp(&b.price()));
+    towers
+}
 
-    let hotbar_items: Vec<_> = towers
-        .iter()
-        .map(|t| {
+/// How many more of `tower` can be placed in a level with `level_limits`, or `None` if it isn't
+/// limited
+fn stock_left(
+    tower: Tower,
+    level_limits: &[(Tower, usize)],
+    placed: &Query<&Tower, With<TowerCell>>,
+) -> Option<usize> {
+    let max = max_per_level(tower, level_limits)?;
+    let count = placed.iter().filter(|placed| **placed == tower).count();
+    Some(max.saturating_sub(count))
+}
+
+fn on_enter_game(
+    mut commands: Commands,
+    assets: Res<UiAssets>,
+    mut selected: ResMut<SelectedElement>,
+) {
+    *selected = SelectedElement::default();
+
+    let hotbar_items: Vec<_> = TowerElement::all()
+        .into_iter()
+        .flat_map(|element| tab_towers(element).into_iter().enumerate())
+        .map(|(i, t)| {
             let asset = assets.hotbar_icons.get(t.ui_asset_key()).unwrap().clone();
-            (*t, asset)
+            (t, asset, i + 1)
         })
         .collect();
 
@@ -44,13 +118,32 @@ fn on_enter_game(mut commands: Commands, assets: Res<UiAssets>) {
         .spawn((
             StateScoped(Screen::Gameplay),
             spawn_hotbar(),
-            Children::spawn(SpawnIter(
-                hotbar_items
-                    .into_iter()
-                    .map(|(tower, icon)| spawn_hotbar_item(tower, icon)),
-            )),
+            children![
+                (
+                    Name::new("Hotbar Tabs"),
+                    Node {
+                        column_gap: Val::Px(8.0),
+                        flex_direction: FlexDirection::Row,
+                        ..default()
+                    },
+                    Children::spawn(SpawnIter(TowerElement::all().into_iter().map(spawn_tab))),
+                ),
+                (
+                    Name::new("Hotbar Items"),
+                    Node {
+                        column_gap: Val::Px(12.0),
+                        align_items: AlignItems::Center,
+                        flex_direction: FlexDirection::Row,
+                        ..default()
+                    },
+                    Children::spawn(SpawnIter(hotbar_items.into_iter().map(
+                        |(tower, icon, shortcut)| spawn_hotbar_item(tower, icon, shortcut)
+                    ))),
+                ),
+            ],
         ))
-        .observe(hotbar_click_observer);
+        .observe(hotbar_click_observer)
+        .observe(hotbar_tab_observer);
 
     commands
         .spawn((
@@ -118,17 +211,36 @@ fn spawn_hotbar() -> impl Bundle {
             margin: UiRect::horizontal(Val::Auto),
             padding: UiRect::axes(Val::Px(8.0), Val::Px(8.0)),
             display: Display::Flex,
-            column_gap: Val::Px(12.0),
+            row_gap: Val::Px(8.0),
             align_items: AlignItems::Center,
             justify_content: JustifyContent::Center,
-            flex_direction: FlexDirection::Row,
+            flex_direction: FlexDirection::Column,
             ..default()
         },
         BackgroundColor(Color::BLACK.with_alpha(0.75)),
     )
 }
 
-fn spawn_hotbar_item(tower: Tower, icon: Handle<Image>) -> impl Bundle {
+fn spawn_tab(element: TowerElement) -> impl Bundle {
+    (
+        Name::new(format!("{} Tab", element.name())),
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(8.0), Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::WHITE.with_alpha(0.1)),
+        BorderRadius::all(Val::Px(4.0)),
+        HotbarTab(element),
+        children![(
+            Text::new(element.name()),
+            TextFont::from_font_size(14.0),
+            Pickable::IGNORE
+        )],
+    )
+}
+
+fn spawn_hotbar_item(tower: Tower, icon: Handle<Image>, shortcut: usize) -> impl Bundle {
     (
         Name::new(tower.name()),
         Button,
@@ -173,44 +285,228 @@ fn spawn_hotbar_item(tower: Tower, icon: Handle<Image>) -> impl Bundle {
                     ..default()
                 },
                 Pickable::IGNORE,
-                ImageNode::new(icon)
+                ImageNode::new(icon).with_color(tower.sprite_tint().unwrap_or(Color::WHITE))
+            ),
+            (
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(4.0),
+                    top: Val::Px(2.0),
+                    ..default()
+                },
+                Text::new(shortcut.to_string()),
+                TextFont::from_font_size(14.0),
+                TextColor(LABEL_TEXT),
+                Pickable::IGNORE,
+            ),
+            (
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(4.0),
+                    bottom: Val::Px(2.0),
+                    ..default()
+                },
+                Text::default(),
+                TextFont::from_font_size(14.0),
+                HotbarStock,
+                Pickable::IGNORE,
             )
         ],
     )
 }
 
+/// Greys out towers that can't be placed right now, and brightens the hovered one
 fn highlight_hovered_tile(
-    mut tile_query: Query<(&Interaction, &Tower, &mut BackgroundColor), With<HotbarItem>>,
+    mut tile_query: Query<
+        (
+            &Interaction,
+            &Tower,
+            &Children,
+            &mut BackgroundColor,
+            &mut BorderColor,
+        ),
+        With<HotbarItem>,
+    >,
+    mut icons: Query<&mut ImageNode>,
+    placed: Query<&Tower, With<TowerCell>>,
     player_state: Res<PlayerState>,
+    unlocked: Res<UnlockedTowers>,
+    level_data: Res<LevelData>,
+    level: Res<CurrentLoadedLevel>,
 ) {
-    for (interaction, tower, mut background_color) in &mut tile_query {
-        background_color.0 = match interaction {
-            Interaction::None => tailwind::SLATE_50.with_alpha(0.25).into(),
-            _ => {
-                if player_state.can_afford(tower.price()) {
-                    tailwind::SLATE_50.with_alpha(0.8).into()
+    let level_limits = level_data.tower_limits(level.0);
+    for (interaction, tower, children, mut background_color, mut border_color) in &mut tile_query {
+        let locked = !unlocked.contains(*tower);
+        let available = !locked
+            && player_state.can_afford(tower.price())
+            && stock_left(*tower, level_limits, &placed) != Some(0);
+
+        background_color.0 = match (interaction, available) {
+            (_, false) => tailwind::SLATE_900.with_alpha(0.6).into(),
+            (Interaction::None, true) => tailwind::SLATE_50.with_alpha(0.25).into(),
+            (_, true) => tailwind::SLATE_50.with_alpha(0.8).into(),
+        };
+        border_color.0 = if available {
+            Color::WHITE
+        } else {
+            tailwind::SLATE_500.into()
+        };
+        for child in children.iter() {
+            if let Ok(mut icon) = icons.get_mut(child) {
+                // Locked towers are shown as a silhouette
+                icon.color = if locked {
+                    Color::srgba(0.05, 0.05, 0.05, 0.9)
+                } else if available {
+                    tower.sprite_tint().unwrap_or(Color::WHITE)
                 } else {
-                    tailwind::SLATE_50.with_alpha(0.25).into()
+                    Color::srgba(0.4, 0.4, 0.4, 0.8)
+                };
+            }
+        }
+    }
+}
+
+fn update_stock(
+    items: Query<(&Tower, &Children), With<HotbarItem>>,
+    mut labels: Query<&mut Text, With<HotbarStock>>,
+    placed: Query<&Tower, With<TowerCell>>,
+    level_data: Res<LevelData>,
+    level: Res<CurrentLoadedLevel>,
+) {
+    let level_limits = level_data.tower_limits(level.0);
+    for (tower, children) in items.iter() {
+        let stock = stock_left(*tower, level_limits, &placed)
+            .zip(max_per_level(*tower, level_limits))
+            .map(|(left, max)| format!("{left}/{max}"))
+            .unwrap_or_default();
+        for child in children.iter() {
+            if let Ok(mut text) = labels.get_mut(child) {
+                if text.0 != stock {
+                    text.0 = stock.clone();
                 }
             }
         }
     }
 }
 
+fn show_selected_tab(
+    selected: Res<SelectedElement>,
+    mut tabs: Query<(&HotbarTab, &mut BackgroundColor)>,
+    mut items: Query<(&Tower, &mut Node), With<HotbarItem>>,
+) {
+    for (HotbarTab(element), mut background_color) in tabs.iter_mut() {
+        background_color.0 = if *element == selected.0 {
+            Color::WHITE.with_alpha(0.4)
+        } else {
+            Color::WHITE.with_alpha(0.1)
+        };
+    }
+    for (tower, mut node) in items.iter_mut() {
+        node.display = if tower.element() == selected.0 {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+}
+
+fn next_tab(mut selected: ResMut<SelectedElement>) {
+    let elements = TowerElement::all();
+    let i = elements.iter().position(|e| *e == selected.0).unwrap_or(0);
+    selected.0 = elements[(i + 1) % elements.len()];
+}
+
+fn select_with_shortcut(
+    input: Res<ButtonInput<KeyCode>>,
+    selected: Res<SelectedElement>,
+    mut pointer_input_state: ResMut<NextState<PointerInteractionState>>,
+    placed: Query<&Tower, With<TowerCell>>,
+    player_state: Res<PlayerState>,
+    unlocked: Res<UnlockedTowers>,
+    level_data: Res<LevelData>,
+    level: Res<CurrentLoadedLevel>,
+    mut commands: Commands,
+) {
+    let Some(i) = SHORTCUT_KEYS
+        .iter()
+        .position(|key| input.just_pressed(*key))
+    else {
+        return;
+    };
+    if let Some(tower) = tab_towers(selected.0).get(i) {
+        select_tower(
+            *tower,
+            &player_state,
+            &unlocked,
+            level_data.tower_limits(level.0),
+            &placed,
+            &mut pointer_input_state,
+            &mut commands,
+        );
+    }
+}
+
+fn hotbar_tab_observer(
+    trigger: Trigger<Pointer<Click>>,
+    tabs: Query<&HotbarTab>,
+    mut selected: ResMut<SelectedElement>,
+) {
+    if let Ok(HotbarTab(element)) = tabs.get(trigger.target) {
+        selected.set_if_neq(SelectedElement(*element));
+    }
+}
+
 fn hotbar_click_observer(
     trigger: Trigger<Pointer<Click>>,
     mut pointer_input_state: ResMut<NextState<PointerInteractionState>>,
     mut commands: Commands,
-    hotbar_items: Query<&Tower>,
+    hotbar_items: Query<&Tower, With<HotbarItem>>,
+    placed: Query<&Tower, With<TowerCell>>,
     player_state: Res<PlayerState>,
+    unlocked: Res<UnlockedTowers>,
+    level_data: Res<LevelData>,
+    level: Res<CurrentLoadedLevel>,
 ) {
     let Ok(tower) = hotbar_items.get(trigger.target) else {
         return;
     };
+    select_tower(
+        *tower,
+        &player_state,
+        &unlocked,
+        level_data.tower_limits(level.0),
+        &placed,
+        &mut pointer_input_state,
+        &mut commands,
+    );
+}
 
+fn select_tower(
+    tower: Tower,
+    player_state: &PlayerState,
+    unlocked: &UnlockedTowers,
+    level_limits: &[(Tower, usize)],
+    placed: &Query<&Tower, With<TowerCell>>,
+    pointer_input_state: &mut NextState<PointerInteractionState>,
+    commands: &mut Commands,
+) {
+    if !unlocked.contains(tower) {
+        commands.trigger(DisplayFlashMessage::new(format!(
+            "{} hasn't been unlocked yet",
+            tower.name()
+        )));
+        return;
+    }
     if !player_state.can_afford(tower.price()) {
         commands.trigger(DisplayFlashMessage::new("Insufficient funds"));
         return;
     }
-    pointer_input_state.set(PointerInteractionState::Placing(*tower));
+    if stock_left(tower, level_limits, placed) == Some(0) {
+        commands.trigger(DisplayFlashMessage::new(format!(
+            "No more {} can be placed on this level",
+            tower.name()
+        )));
+        return;
+    }
+    pointer_input_state.set(PointerInteractionState::Placing(tower));
 }
@@ -1,5 +1,6 @@
 use crate::data::PlayerState;
-use crate::demo::enemy_health::BountyEarned;
+use crate::demo::enemy_health::{BountyEarned, EnemyHealth};
+use crate::gameplay::boss::Boss;
 use crate::gameplay::shared_systems::Lifetime;
 use crate::gameplay::wave_manager::WaveManager;
 use crate::level::resource::CurrentLoadedLevel;
@@ -20,6 +21,7 @@ pub(super) fn plugin(app: &mut App) {
         update_wave_tracker.run_if(resource_changed::<WaveManager>),
     );
     app.add_systems(Update, animate_bounty_text);
+    app.add_systems(Update, update_boss_bar.run_if(in_state(Screen::Gameplay)));
     app.add_observer(bounty_earned);
 }
 
@@ -31,6 +33,15 @@ enum HudElement {
     BountyEarned,
 }
 
+#[derive(Component)]
+struct BossBar;
+
+#[derive(Component)]
+struct BossBarLabel;
+
+#[derive(Component)]
+struct BossBarFill;
+
 fn on_enter_game(mut commands: Commands) {
     commands.spawn((
         StateScoped(Screen::Gameplay),
@@ -62,6 +73,79 @@ fn on_enter_game(mut commands: Commands) {
             ),
         ],
     ));
+
+    commands.spawn((
+        StateScoped(Screen::Gameplay),
+        Name::new("Boss Bar"),
+        BossBar,
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(64.0),
+            left: Val::Percent(25.0),
+            width: Val::Percent(50.0),
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        children![
+            (
+                widget::ui_font_color("BOSS", tailwind::RED_100.into()),
+                BossBarLabel
+            ),
+            (
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(14.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::BLACK.with_alpha(0.75)),
+                BorderColor(tailwind::RED_900.into()),
+                children![(
+                    BossBarFill,
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(tailwind::RED_600.into()),
+                )]
+            ),
+        ],
+    ));
+}
+
+fn update_boss_bar(
+    bosses: Query<(&Boss, &EnemyHealth, Option<&Name>), Without<Lifetime>>,
+    mut bar: Query<&mut Visibility, With<BossBar>>,
+    mut label: Query<&mut Text, With<BossBarLabel>>,
+    mut fill: Query<&mut Node, With<BossBarFill>>,
+) {
+    let Ok(mut visibility) = bar.single_mut() else {
+        return;
+    };
+    let Some((boss, health, name)) = bosses.iter().find(|(_, health, _)| health.current() > 0)
+    else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    *visibility = Visibility::Inherited;
+
+    if let Ok(mut text) = label.single_mut() {
+        let name = name.map(|n| n.as_str()).unwrap_or("Boss");
+        text.0 = format!(
+            "{name} - phase {}/{}",
+            boss.phase_index() + 1,
+            boss.phase_count()
+        );
+    }
+    if let Ok(mut node) = fill.single_mut() {
+        node.width = Val::Percent(health.fraction() * 100.0);
+    }
 }
 
 fn update_hud(player_state: Res<PlayerState>, mut hud_elements: Query<(&mut Text, &HudElement)>) {
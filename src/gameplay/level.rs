@@ -2,11 +2,13 @@ use crate::assets::SoundEffects;
 use crate::audio::sound_effect;
 use crate::data::levels::LevelData;
 use crate::gameplay::wave_manager::WaveManager;
-use crate::level::resource::{CurrentLoadedLevel, GotoNextLevel, LevelSelect, UnlockedLevels};
+use crate::level::resource::{
+    CurrentLoadedLevel, GotoNextLevel, LevelSelect, UnlockedLevels, UnlockedTowers,
+};
 use crate::{
     assets::LevelAssets,
     data::PlayerState,
-    demo::enemy_health::EnemyHealth,
+    demo::enemy_health::{EnemyHealth, LivesPenalty},
     level::{
         components::{EndNode, LevelParent},
         resource::Level,
@@ -79,6 +81,8 @@ pub fn unlock_next_level(
     level_select: Res<LevelSelect>,
     current_loaded_level: Res<CurrentLoadedLevel>,
     mut unlocked_levels: ResMut<UnlockedLevels>,
+    mut unlocked_towers: ResMut<UnlockedTowers>,
+    level_data: Res<LevelData>,
     enemies: Query<(), With<EnemyHealth>>,
 ) {
     if current_loaded_level.0 != level_select.0 {
@@ -91,6 +95,7 @@ pub fn unlock_next_level(
         let next_level = level_select.0 + 1;
         if !unlocked_levels.0.contains(&next_level) {
             unlocked_levels.0.push(next_level);
+            unlocked_towers.unlock(level_data.new_towers(next_level));
         }
     }
 }
@@ -110,16 +115,16 @@ pub fn goto_next_level(
 pub fn despawn_enemy_on_goal(
     mut commands: Commands,
     mut game_state: ResMut<PlayerState>,
-    enemies: Query<(Entity, &Transform), With<EnemyHealth>>,
+    enemies: Query<(Entity, &Transform, Option<&LivesPenalty>), With<EnemyHealth>>,
     goal: Query<&Transform, With<EndNode>>,
     sfx: Res<SoundEffects>,
 ) {
     if let Ok(goal_pos) = goal.single() {
         let goal_pos = goal_pos.translation.xy();
-        for (e, pos) in enemies.iter() {
+        for (e, pos, penalty) in enemies.iter() {
             if pos.translation.xy().distance(goal_pos) < 7. {
                 commands.get_entity(e).unwrap().despawn();
-                game_state.health -= 1;
+                game_state.health -= penalty.copied().unwrap_or_default().0;
                 commands.spawn(sound_effect(sfx.took_damage.clone()));
                 println!("Damage Taken!");
             }
@@ -2,17 +2,25 @@ use bevy::prelude::*;
 
 pub mod animation;
 mod background;
+pub mod blueprints;
+pub mod boss;
 mod damage_numbers;
+mod enemy_tooltip;
+pub mod grid_cursor;
 pub mod hotbar;
 pub mod hud;
 pub mod level;
 pub mod messages;
+pub mod selling;
 pub mod shared_systems;
 pub mod stats;
 pub mod status_effects;
+pub mod tower_coverage;
+pub mod tower_inspection;
 pub mod tower_placement;
 pub mod towers;
 pub mod wave_manager;
+mod wave_preview;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_plugins((
@@ -25,9 +33,18 @@ pub(super) fn plugin(app: &mut App) {
         status_effects::plugin,
         stats::plugin,
         towers::plugin,
+        (
+            tower_inspection::plugin,
+            enemy_tooltip::plugin,
+            tower_coverage::plugin,
+            grid_cursor::plugin,
+            selling::plugin,
+            blueprints::plugin,
+        ),
         tower_placement::plugin,
-        wave_manager::plugin,
+        (wave_manager::plugin, wave_preview::plugin),
         messages::plugin,
         background::plugin,
+        boss::plugin,
     ));
 }
@@ -0,0 +1,266 @@
+use crate::data::levels::LevelData;
+use crate::data::placement::can_place;
+use crate::data::selling::SellConfig;
+use crate::data::{PlayerState, PointerInteractionState, Tower};
+use crate::gameplay::grid_cursor::GridCursor;
+use crate::gameplay::messages::DisplayFlashMessage;
+use crate::gameplay::tower_placement::placement_world;
+use crate::gameplay::wave_manager::WaveManager;
+use crate::level::components::Adjacent;
+use crate::level::resource::{CellDirection, CurrentLoadedLevel};
+use crate::prelude::*;
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use bevy_composable::app_impl::{ComplexSpawnable, ComponentTreeable};
+
+const SELL_KEYS: [KeyCode; 2] = [KeyCode::Delete, KeyCode::Backspace];
+const UNDO_KEY: KeyCode = KeyCode::KeyZ;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Invested>().register_type::<SellGrace>();
+    app.init_resource::<LastSale>();
+    app.add_event::<SellTower>();
+    app.add_observer(sell_on_right_click);
+    app.add_systems(
+        Update,
+        (
+            (start_tracking_investment, tick_sell_grace),
+            (sell_with_keys, sell_with_gamepad),
+            sell_towers,
+            (
+                tick_last_sale,
+                undo_last_sale.run_if(input_just_pressed(UNDO_KEY).or(undo_on_gamepad)),
+            )
+                .chain(),
+        )
+            .chain()
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), forget_last_sale);
+}
+
+/// Everything spent on a tower, which is what selling it refunds from
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub struct Invested(pub i32);
+
+/// Towers can be sold for a full refund shortly after being placed, or any time before the next
+/// wave starts if they were placed between waves
+#[derive(Component, Clone, Debug, PartialEq, Eq, Reflect)]
+pub struct SellGrace {
+    pub timer: Timer,
+    /// `WaveManager::remaining_waves` when the tower was placed, if no wave was running
+    pub placed_before_wave: Option<usize>,
+}
+
+impl SellGrace {
+    fn full_refund(&self, wave_manager: &WaveManager) -> bool {
+        let wave_not_started = self.placed_before_wave.is_some_and(|remaining| {
+            wave_manager.current_wave.is_none() && wave_manager.remaining_waves() == remaining
+        });
+        !self.timer.finished() || wave_not_started
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub struct SellTower(pub Entity);
+
+/// The most recent sale, kept around long enough to be undone
+#[derive(Resource, Default, Debug)]
+struct LastSale(Option<Sale>);
+
+#[derive(Debug)]
+struct Sale {
+    tower: Tower,
+    orientation: CellDirection,
+    panel: Entity,
+    refund: i32,
+    invested: Invested,
+    grace: SellGrace,
+    undo_timer: Timer,
+}
+
+fn start_tracking_investment(
+    towers: Query<(Entity, &Tower), (Added<Tower>, Without<Invested>)>,
+    wave_manager: Res<WaveManager>,
+    config: Res<SellConfig>,
+    mut commands: Commands,
+) {
+    for (e, tower) in towers.iter() {
+        let placed_before_wave = wave_manager
+            .current_wave
+            .is_none()
+            .then(|| wave_manager.remaining_waves());
+        commands.entity(e).insert((
+            Invested(tower.price()),
+            SellGrace {
+                timer: Timer::from_seconds(config.grace_period, TimerMode::Once),
+                placed_before_wave,
+            },
+        ));
+    }
+}
+
+fn tick_sell_grace(mut towers: Query<&mut SellGrace>, time: Res<Time>) {
+    for mut grace in towers.iter_mut() {
+        grace.timer.tick(time.delta());
+    }
+}
+
+fn sell_on_right_click(
+    trigger: Trigger<Pointer<Click>>,
+    towers: Query<(), With<Tower>>,
+    mut sales: EventWriter<SellTower>,
+) {
+    // Clicks bubble up through the hierarchy, so only react to the entity that was clicked
+    if trigger.target() != trigger.target || trigger.event().button != PointerButton::Secondary {
+        return;
+    }
+    if towers.contains(trigger.target) {
+        sales.write(SellTower(trigger.target));
+    }
+}
+
+fn sell_with_keys(
+    input: Res<ButtonInput<KeyCode>>,
+    pointer_state: Res<State<PointerInteractionState>>,
+    cursor: Res<GridCursor>,
+    panels: Query<(&Adjacent, &Children)>,
+    towers: Query<(), With<Tower>>,
+    mut sales: EventWriter<SellTower>,
+) {
+    if *pointer_state.get() != PointerInteractionState::Selecting
+        || !input.any_just_pressed(SELL_KEYS)
+    {
+        return;
+    }
+    if let Some(tower) = tower_at_cursor(&cursor, &panels, &towers) {
+        sales.write(SellTower(tower));
+    }
+}
+
+fn sell_with_gamepad(
+    gamepads: Query<&Gamepad>,
+    pointer_state: Res<State<PointerInteractionState>>,
+    cursor: Res<GridCursor>,
+    panels: Query<(&Adjacent, &Children)>,
+    towers: Query<(), With<Tower>>,
+    mut sales: EventWriter<SellTower>,
+) {
+    if *pointer_state.get() != PointerInteractionState::Selecting
+        || !gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::West))
+    {
+        return;
+    }
+    if let Some(tower) = tower_at_cursor(&cursor, &panels, &towers) {
+        sales.write(SellTower(tower));
+    }
+}
+
+fn tower_at_cursor(
+    cursor: &GridCursor,
+    panels: &Query<(&Adjacent, &Children)>,
+    towers: &Query<(), With<Tower>>,
+) -> Option<Entity> {
+    panels
+        .iter()
+        .filter(|(adjacent, _)| adjacent.cell() == cursor.cell && adjacent.face() == cursor.face)
+        .flat_map(|(_, children)| children.iter())
+        .find(|child| towers.contains(*child))
+}
+
+fn sell_towers(
+    mut sales: EventReader<SellTower>,
+    towers: Query<(&Tower, &CellDirection, &ChildOf, &Invested, &SellGrace)>,
+    wave_manager: Res<WaveManager>,
+    config: Res<SellConfig>,
+    mut player_state: ResMut<PlayerState>,
+    mut last_sale: ResMut<LastSale>,
+    mut commands: Commands,
+) {
+    for SellTower(entity) in sales.read() {
+        let Ok((tower, orientation, panel, invested, grace)) = towers.get(*entity) else {
+            continue;
+        };
+        let refund = config.refund(invested.0, grace.full_refund(&wave_manager));
+        player_state.money += refund;
+        commands.entity(*entity).despawn();
+        commands.trigger(DisplayFlashMessage::new(format!(
+            "Sold {} for {refund}. Press Z to undo",
+            tower.name()
+        )));
+
+        last_sale.0 = Some(Sale {
+            tower: *tower,
+            orientation: *orientation,
+            panel: panel.0,
+            refund,
+            invested: *invested,
+            grace: grace.clone(),
+            undo_timer: Timer::from_seconds(config.undo_window, TimerMode::Once),
+        });
+    }
+}
+
+fn tick_last_sale(mut last_sale: ResMut<LastSale>, time: Res<Time>) {
+    let Some(sale) = last_sale.0.as_mut() else {
+        return;
+    };
+    if sale.undo_timer.tick(time.delta()).finished() {
+        last_sale.0 = None;
+    }
+}
+
+fn undo_on_gamepad(gamepads: Query<&Gamepad>) -> bool {
+    gamepads
+        .iter()
+        .any(|gamepad| gamepad.just_pressed(GamepadButton::North))
+}
+
+/// Puts the last sold tower back where it was, as long as the refund hasn't been spent and the
+/// tower could still be placed there
+fn undo_last_sale(
+    mut last_sale: ResMut<LastSale>,
+    mut player_state: ResMut<PlayerState>,
+    level_data: Res<LevelData>,
+    level: Res<CurrentLoadedLevel>,
+    towers: Query<(&ChildOf, &Tower)>,
+    adjacent_placements: Query<&Adjacent>,
+    mut commands: Commands,
+) {
+    let Some(sale) = last_sale.0.as_ref() else {
+        return;
+    };
+    let Ok(target) = adjacent_placements.get(sale.panel) else {
+        return;
+    };
+    let mut world = placement_world(
+        &player_state,
+        level_data.tower_limits(level.0),
+        &towers,
+        &adjacent_placements,
+    );
+    // Undoing costs the refund rather than the tower's price
+    world.money += sale.tower.price() - sale.refund;
+    if let Err(reason) = can_place(sale.tower, target, &world) {
+        commands.trigger(DisplayFlashMessage::new(reason.to_string()));
+        return;
+    }
+
+    let Some(sale) = last_sale.0.take() else {
+        return;
+    };
+    player_state.money -= sale.refund;
+    commands.entity(sale.panel).with_children(|commands| {
+        commands.compose(
+            crate::prefabs::towers::tower(sale.tower, sale.orientation)
+                + sale.orientation.sprite_offset(&sale.tower).store()
+                + (sale.invested, sale.grace).store(),
+        );
+    });
+}
+
+fn forget_last_sale(mut last_sale: ResMut<LastSale>) {
+    last_sale.0 = None;
+}
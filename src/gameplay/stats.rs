@@ -11,7 +11,10 @@ use crate::{
     PausableSystems,
     data::{
         projectiles::DamageType,
-        stats::{DamageMultiplier, MoveSpeed, Stat, StatFriction, StatTrait},
+        stats::{
+            Armor, DamageMultiplier, ExtraShots, MoveSpeed, Stat, StatFriction, StatTrait,
+            StatusStrength, TowerCooldown, TowerDamage, TriggerRange,
+        },
     },
     screens::Screen,
 };
@@ -31,6 +34,12 @@ pub(super) fn plugin(app: &mut App) {
 
     implement_stat::<MoveSpeed>(app);
     implement_stat::<StatFriction>(app);
+    implement_stat::<Armor>(app);
+    implement_stat::<TowerCooldown>(app);
+    implement_stat::<TowerDamage>(app);
+    implement_stat::<TriggerRange>(app);
+    implement_stat::<StatusStrength>(app);
+    implement_stat::<ExtraShots>(app);
     implement_stat::<DamageMultiplier<{ DamageType::Physical }>>(app);
     implement_stat::<DamageMultiplier<{ DamageType::Cold }>>(app);
     implement_stat::<DamageMultiplier<{ DamageType::Burning }>>(app);
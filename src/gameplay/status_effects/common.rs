@@ -1,24 +1,28 @@
 use bevy::{
     ecs::{
+        component::Component,
         entity::Entity,
         event::{Event, EventReader, EventWriter},
-        query::With,
+        query::{Added, Changed, With},
+        removal_detection::RemovedComponents,
         schedule::{IntoScheduleConfigs, ScheduleConfigs},
         system::{Commands, Local, Query, Res, ScheduleSystem},
     },
     reflect::Reflect,
     time::{Time, Timer},
 };
-use std::marker::PhantomData;
+use std::{collections::HashMap, marker::PhantomData};
 
 use crate::{
     data::{
         stats::{Stat, StatTrait},
         status_effects::{
-            StatusEffect, StatusEffectTrait, StatusEnum, damage_multiplier, duration_multiplier,
+            StatusEffect, StatusEffectTrait, StatusEnum, StatusResistances, damage_multiplier,
+            duration_multiplier,
         },
     },
     demo::enemy_health::{EnemyHealth, TryDamageToEnemy},
+    gameplay::boss::Boss,
 };
 
 use super::display::StatusAnimation;
@@ -30,10 +34,76 @@ pub struct TryApplyStatus {
     pub strength: usize,
 }
 
-#[derive(Reflect, Debug, Event, PartialEq, Eq)]
+/// A status that made it through the reaction table and is waiting to be applied
+#[derive(Reflect, Debug, Event, PartialEq, Eq, Clone, Copy)]
+pub struct PendingStatus(pub TryApplyStatus);
+
+#[derive(Reflect, Debug, Event, PartialEq, Eq, Clone, Copy)]
+pub struct TryRemoveStatus {
+    pub status: StatusEnum,
+    pub enemy: Entity,
+}
+
+/// Untyped mirror of every `StatusEffect<T>` on an enemy, with their strengths and timers
+#[derive(Component, Reflect, Debug, Default, PartialEq, Eq, Clone)]
+pub struct ActiveStatuses(HashMap<StatusEnum, ActiveStatus>);
+
+#[derive(Reflect, Debug, PartialEq, Eq, Clone)]
+pub struct ActiveStatus {
+    pub strength: usize,
+    pub stacks: usize,
+    /// A copy of the status's `timer`, so independent stacks show their longest remaining one
+    pub duration: Timer,
+}
+
+impl ActiveStatus {
+    /// How much of the status is left, from 1 when freshly applied down to 0
+    pub fn remaining_fraction(&self) -> f32 {
+        self.duration.fraction_remaining()
+    }
+}
+
+impl ActiveStatuses {
+    pub fn strength(&self, status: StatusEnum) -> Option<usize> {
+        self.0.get(&status).map(|active| active.strength)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (StatusEnum, usize)> + '_ {
+        self.0
+            .iter()
+            .map(|(status, active)| (*status, active.strength))
+    }
+
+    pub fn get(&self, status: StatusEnum) -> Option<&ActiveStatus> {
+        self.0.get(&status)
+    }
+
+    /// Statuses in a stable order, for showing to the player
+    pub fn sorted(&self) -> Vec<(StatusEnum, &ActiveStatus)> {
+        let mut statuses: Vec<_> = self
+            .0
+            .iter()
+            .map(|(status, active)| (*status, active))
+            .collect();
+        statuses.sort_by_key(|(status, _)| *status as usize);
+        statuses
+    }
+}
+
+/// Sent when an enemy's resistances shrug off a status, or weaken or shorten it
+#[derive(Reflect, Debug, Event, PartialEq, Eq, Clone, Copy)]
+pub struct StatusResisted {
+    pub status: StatusEnum,
+    pub enemy: Entity,
+    /// Whether some of the status still got through
+    pub partial: bool,
+}
+
+#[derive(Reflect, Debug, Event, PartialEq)]
 pub struct ApplyStatus<T: StatusEffectTrait> {
     pub enemy: Entity,
     pub strength: usize,
+    pub duration_multiplier: f32,
     #[reflect(ignore)]
     _phantom: PhantomData<T>,
 }
@@ -69,6 +139,7 @@ pub fn periodic_damage<T: StatusEffectTrait>(dps: isize) -> ScheduleConfigs<Sche
                     damage_type: T::damage_element(),
                     enemy: enemy,
                     strength: effect.strength,
+                    source: None,
                 });
             }
             cooldown.0.reset();
@@ -112,35 +183,79 @@ pub fn status_debuff_postmul_flat<S: StatusEffectTrait, T: StatTrait>(
 }
 
 pub fn dispatch_typed_events<T: StatusEffectTrait>(
-    mut reader: EventReader<TryApplyStatus>,
+    mut reader: EventReader<PendingStatus>,
     mut writer: EventWriter<ApplyStatus<T>>,
+    mut resisted: EventWriter<StatusResisted>,
+    enemies: Query<(Option<&StatusResistances>, Option<&Boss>)>,
 ) {
-    for TryApplyStatus {
+    for PendingStatus(TryApplyStatus {
         status,
         enemy,
         strength,
-    } in reader
+    }) in reader
         .read()
-        .filter(|w| w.status == T::corresponding_enum())
+        .filter(|w| w.0.status == T::corresponding_enum())
     {
-        writer.write(ApplyStatus::new(*enemy, *strength));
+        let (resistances, boss) = enemies.get(*enemy).unwrap_or_default();
+        let outcome = match resistances {
+            _ if boss.is_some_and(|boss| boss.is_immune(*status)) => None,
+            Some(resistances) => resistances.resolve(*status, *strength),
+            None => Some((*strength, 1.)),
+        };
+
+        match outcome {
+            Some((resisted_strength, duration)) => {
+                if resisted_strength < *strength || duration < 1. {
+                    resisted.write(StatusResisted {
+                        status: *status,
+                        enemy: *enemy,
+                        partial: true,
+                    });
+                }
+                writer.write(
+                    ApplyStatus::new(*enemy, resisted_strength).with_duration_multiplier(duration),
+                );
+            }
+            None => {
+                resisted.write(StatusResisted {
+                    status: *status,
+                    enemy: *enemy,
+                    partial: false,
+                });
+            }
+        }
     }
 }
 
 pub fn apply_status_effects<T: StatusEffectTrait>(
     mut events: EventReader<ApplyStatus<T>>,
+    mut existing: Query<&mut StatusEffect<T>>,
     mut commands: Commands,
 ) {
+    let mut added: HashMap<Entity, StatusEffect<T>> = HashMap::new();
     for ApplyStatus {
         enemy,
         strength,
+        duration_multiplier: resisted_duration,
         _phantom,
     } in events.read()
     {
-        commands.entity(*enemy).insert(StatusEffect::<T>::new(
-            *strength,
-            T::base_duration() * duration_multiplier(*strength),
-        ));
+        let duration_for = |strength: usize| {
+            T::base_duration() * duration_multiplier(strength) * resisted_duration
+        };
+        let duration = duration_for(*strength);
+
+        if let Ok(mut status) = existing.get_mut(*enemy) {
+            status.merge(*strength, duration, duration_for);
+        } else if let Some(status) = added.get_mut(enemy) {
+            status.merge(*strength, duration, duration_for);
+        } else {
+            added.insert(*enemy, StatusEffect::<T>::new(*strength, duration));
+        }
+    }
+
+    for (enemy, status) in added {
+        commands.entity(enemy).insert(status);
     }
 }
 
@@ -149,7 +264,7 @@ pub fn tick_statuses<T: StatusEffectTrait>(
     time: Res<Time>,
 ) {
     for mut enemy in enemies.iter_mut() {
-        enemy.duration.tick(time.delta());
+        enemy.tick(time.delta());
     }
 }
 
@@ -158,12 +273,81 @@ pub fn timeout_statuses<T: StatusEffectTrait>(
     mut events: EventWriter<RemoveStatus<T>>,
 ) {
     for (enemy, status) in enemies.iter() {
-        if status.duration.finished() {
+        if status.finished() {
             events.write(RemoveStatus::new(enemy, status.strength));
         }
     }
 }
 
+pub fn dispatch_typed_removals<T: StatusEffectTrait>(
+    mut reader: EventReader<TryRemoveStatus>,
+    mut writer: EventWriter<RemoveStatus<T>>,
+    enemies: Query<&StatusEffect<T>>,
+) {
+    for TryRemoveStatus { enemy, .. } in reader
+        .read()
+        .filter(|w| w.status == T::corresponding_enum())
+    {
+        if let Ok(status) = enemies.get(*enemy) {
+            writer.write(RemoveStatus::new(*enemy, status.strength));
+        }
+    }
+}
+
+pub fn sync_active_statuses<T: StatusEffectTrait>(
+    statuses: Query<(Entity, &StatusEffect<T>), Changed<StatusEffect<T>>>,
+    mut enemies: Query<&mut ActiveStatuses>,
+    mut removed: RemovedComponents<StatusEffect<T>>,
+) {
+    for enemy in removed.read() {
+        if let Ok(mut active) = enemies.get_mut(enemy) {
+            active.0.remove(&T::corresponding_enum());
+        }
+    }
+    for (enemy, status) in statuses.iter() {
+        if let Ok(mut active) = enemies.get_mut(enemy) {
+            active.0.insert(
+                T::corresponding_enum(),
+                ActiveStatus {
+                    strength: status.strength,
+                    stacks: status.stack_count(),
+                    duration: status.timer().clone(),
+                },
+            );
+        }
+    }
+}
+
+pub fn run_apply_hooks<T: StatusEffectTrait>(
+    statuses: Query<(Entity, &StatusEffect<T>), Added<StatusEffect<T>>>,
+    mut commands: Commands,
+) {
+    for (enemy, status) in statuses.iter() {
+        T::on_apply(&mut commands, enemy, status.strength);
+    }
+}
+
+pub fn run_tick_hooks<T: StatusEffectTrait>(
+    statuses: Query<(Entity, &StatusEffect<T>)>,
+    mut commands: Commands,
+) {
+    for (enemy, status) in statuses.iter() {
+        T::on_tick(&mut commands, enemy, status.strength);
+    }
+}
+
+pub fn run_remove_hooks<T: StatusEffectTrait>(
+    mut events: EventReader<RemoveStatus<T>>,
+    mut commands: Commands,
+) {
+    for RemoveStatus {
+        enemy, strength, ..
+    } in events.read()
+    {
+        T::on_remove(&mut commands, *enemy, *strength);
+    }
+}
+
 pub fn do_remove_status<T: StatusEffectTrait>(
     mut events: EventReader<RemoveStatus<T>>,
     mut commands: Commands,
@@ -183,9 +367,15 @@ impl<T: StatusEffectTrait> ApplyStatus<T> {
         ApplyStatus {
             enemy,
             strength,
+            duration_multiplier: 1.,
             _phantom: PhantomData,
         }
     }
+
+    pub fn with_duration_multiplier(mut self, duration_multiplier: f32) -> Self {
+        self.duration_multiplier = duration_multiplier;
+        self
+    }
 }
 
 impl<T: StatusEffectTrait> RemoveStatus<T> {
@@ -1,24 +1,35 @@
 use bevy::{
+    color::{Alpha, Color},
     ecs::{
         component::Component,
         entity::Entity,
         event::EventReader,
-        hierarchy::Children,
+        hierarchy::{ChildOf, Children},
         query::With,
+        relationship::RelationshipTarget,
         system::{Commands, Query, Res},
     },
+    gizmos::gizmos::Gizmos,
+    math::{Isometry2d, Vec2, Vec3Swizzles},
     reflect::Reflect,
-    sprite::Sprite,
-    time::Time,
-    transform::components::Transform,
+    transform::components::{GlobalTransform, Transform},
 };
-use std::marker::PhantomData;
+use std::{f32::consts::TAU, marker::PhantomData};
 
 use crate::{
-    assets::StatusSprites, data::status_effects::StatusEffectTrait, prefabs::enemies::EnemySprite,
+    assets::{STATUS_ICON_SIZE, StatusSprites},
+    data::status_effects::{StatusEffectTrait, StatusEnum},
+    demo::enemy_health::EnemyHealthBar,
+    prefabs::enemies::EnemySprite,
 };
 
-use super::common::{ApplyStatus, RemoveStatus};
+use super::common::{ActiveStatuses, ApplyStatus, RemoveStatus};
+
+const STATUS_ICON_GAP: f32 = 0.5;
+/// Strength pips past this many aren't drawn, they'd be wider than the icon
+const MAX_PIPS: usize = 5;
+/// Extra stacks past this many aren't drawn
+const MAX_STACK_OUTLINES: usize = 3;
 
 #[derive(Reflect, Debug, Component, PartialEq, Eq)]
 pub struct StatusAnimation<T> {
@@ -26,21 +37,13 @@ pub struct StatusAnimation<T> {
     _phantom: PhantomData<T>,
 }
 
-pub fn animate_status_effect<T: StatusEffectTrait>(
-    time: Res<Time>,
-    mut enemies: Query<(Entity, &mut Sprite), With<StatusAnimation<T>>>,
-) {
-    for (entity, mut sprite) in enemies.iter_mut() {
-        if let Some(atlas) = &mut sprite.texture_atlas {
-            if atlas.index >= 5 {
-                atlas.index = 0
-            } else {
-                atlas.index += 1
-            }
-        }
-    }
-}
+/// Untyped marker for the icon a status puts above its enemy, so the icons of every status can be
+/// laid out together
+#[derive(Reflect, Debug, Component, Clone, Copy, PartialEq, Eq)]
+pub struct StatusIcon(pub StatusEnum);
 
+/// Spawns the status's icon on the enemy, unless it already has one. The icon animates through
+/// its `AnimationFrameQueue`, so it keeps the same frame rate as every other animated sprite.
 pub fn add_status_animation<T: StatusEffectTrait>(
     mut events: EventReader<ApplyStatus<T>>,
     children: Query<&Children>,
@@ -54,29 +57,30 @@ pub fn add_status_animation<T: StatusEffectTrait>(
         let e = event.enemy;
 
         // Get the entity that holds the enemy's sprite
-        let enemy_sprite_entity = children
+        let Some(enemy_sprite_entity) = children
             .get(e)
-            .unwrap()
-            .iter()
-            .filter(|w| enemy_sprites.get(**w).is_ok())
-            .next()
-            .unwrap();
+            .ok()
+            .and_then(|children| children.iter().find(|w| enemy_sprites.contains(*w)))
+        else {
+            continue;
+        };
 
-        // Get rid of any existing Entity in charge of representing this status effect
-        if let Ok(children_) = children.get(*enemy_sprite_entity) {
-            for entity in children_
+        // Reapplying a status updates the existing icon's pips and timer instead
+        let already_shown = children.get(enemy_sprite_entity).is_ok_and(|children_| {
+            children_
                 .iter()
-                .filter(|w| existing_status_animations.get(**w).is_ok())
-            {
-                commands.entity(*entity).despawn();
-            }
+                .any(|w| existing_status_animations.contains(w))
+        });
+        if already_shown {
+            continue;
         }
 
         let status_sprite_bundle = sprites.status_bundle(T::corresponding_enum());
 
-        commands.entity(*enemy_sprite_entity).with_children(|p| {
+        commands.entity(enemy_sprite_entity).with_children(|p| {
             p.spawn((
                 StatusAnimation::<T>::new(),
+                StatusIcon(T::corresponding_enum()),
                 status_sprite_bundle,
                 Transform::default(),
             ));
@@ -103,6 +107,97 @@ pub fn remove_status_animation_on_timeout<T: StatusEffectTrait>(
     }
 }
 
+/// Lines up each enemy's status icons in a centered row just above its health bar
+pub fn layout_status_icons(
+    enemy_sprites: Query<&Children, With<EnemySprite>>,
+    sprite_children: Query<&Children, Without<EnemySprite>>,
+    health_bars: Query<(), With<EnemyHealthBar>>,
+    mut transforms: Query<&mut Transform>,
+    icons: Query<&StatusIcon>,
+) {
+    for children in enemy_sprites.iter() {
+        // The health bar's fill sits inside a background, which is what's offset above the sprite
+        let bar_height = children
+            .iter()
+            .find(|child| {
+                sprite_children
+                    .get(*child)
+                    .is_ok_and(|inner| inner.iter().any(|fill| health_bars.contains(fill)))
+            })
+            .and_then(|background| transforms.get(background).ok())
+            .map_or(0., |transform| transform.translation.y);
+
+        let mut row: Vec<_> = children
+            .iter()
+            .filter_map(|child| icons.get(child).ok().map(|icon| (child, icon.0)))
+            .collect();
+        row.sort_by_key(|(_, status)| *status as usize);
+
+        let width = row.len() as f32 * (STATUS_ICON_SIZE + STATUS_ICON_GAP) - STATUS_ICON_GAP;
+        for (i, (icon, _)) in row.into_iter().enumerate() {
+            let Ok(mut transform) = transforms.get_mut(icon) else {
+                continue;
+            };
+            let x = -width / 2.
+                + STATUS_ICON_SIZE / 2.
+                + i as f32 * (STATUS_ICON_SIZE + STATUS_ICON_GAP);
+            let y = bar_height + STATUS_ICON_SIZE / 2. + STATUS_ICON_GAP;
+            transform.translation.x = x;
+            transform.translation.y = y;
+            transform.translation.z = 1.;
+        }
+    }
+}
+
+/// Draws how long each status has left as a ring that empties around its icon, its strength as
+/// pips over it, and extra stacks as outlines fanned out behind it
+pub fn draw_status_indicators(
+    icons: Query<(&StatusIcon, &GlobalTransform, &ChildOf)>,
+    parents: Query<&ChildOf>,
+    enemies: Query<&ActiveStatuses>,
+    mut gizmos: Gizmos,
+) {
+    for (StatusIcon(status), transform, ChildOf(sprite)) in icons.iter() {
+        let Some(active) = parents
+            .get(*sprite)
+            .ok()
+            .and_then(|ChildOf(enemy)| enemies.get(*enemy).ok())
+            .and_then(|statuses| statuses.get(*status))
+        else {
+            continue;
+        };
+        let center = transform.translation().xy();
+        let color = status.color();
+
+        gizmos.arc_2d(
+            Isometry2d::from_translation(center),
+            TAU * active.remaining_fraction(),
+            STATUS_ICON_SIZE * 0.65,
+            color,
+        );
+
+        let pips = active.strength.min(MAX_PIPS);
+        let pip_spacing = STATUS_ICON_SIZE / MAX_PIPS as f32;
+        let pips_start = center.x - (pips as f32 - 1.) * pip_spacing / 2.;
+        for i in 0..pips {
+            let pip = Vec2::new(
+                pips_start + i as f32 * pip_spacing,
+                center.y + STATUS_ICON_SIZE * 0.8,
+            );
+            gizmos.circle_2d(Isometry2d::from_translation(pip), pip_spacing * 0.3, color);
+        }
+
+        for i in 1..active.stacks.min(MAX_STACK_OUTLINES + 1) {
+            let offset = Vec2::splat(0.3 * i as f32);
+            gizmos.rect_2d(
+                Isometry2d::from_translation(center + offset),
+                Vec2::splat(STATUS_ICON_SIZE),
+                Color::WHITE.with_alpha(0.5),
+            );
+        }
+    }
+}
+
 impl<T: StatusEffectTrait> StatusAnimation<T> {
     pub fn new() -> StatusAnimation<T> {
         StatusAnimation {
@@ -0,0 +1,28 @@
+use bevy::ecs::{
+    event::{EventReader, EventWriter},
+    query::With,
+    system::Query,
+};
+
+use super::common::{RemoveStatus, TryApplyStatus};
+use crate::data::status_effects::{Burned, Oiled, StatusEffect, StatusEnum, cooked_after_burn};
+
+/// Burns that run out leave the enemy cooked and weak to cold, unless it was oiled
+pub fn cook_after_burn(
+    mut removed: EventReader<RemoveStatus<Burned>>,
+    oiled: Query<(), With<StatusEffect<Oiled>>>,
+    mut status_events: EventWriter<TryApplyStatus>,
+) {
+    for RemoveStatus {
+        enemy, strength, ..
+    } in removed.read()
+    {
+        if let Some(strength) = cooked_after_burn(*strength, oiled.contains(*enemy)) {
+            status_events.write(TryApplyStatus {
+                status: StatusEnum::Cooked,
+                enemy: *enemy,
+                strength,
+            });
+        }
+    }
+}
@@ -1,18 +1,21 @@
 use bevy::{
     app::{App, FixedUpdate, Update},
-    ecs::schedule::IntoScheduleConfigs,
+    ecs::schedule::{IntoScheduleConfigs, SystemSet},
     reflect::Reflect,
     state::condition::in_state,
-    time::common_conditions::on_timer,
 };
 use common::{
-    ApplyStatus, RemoveStatus, TryApplyStatus, apply_status_effects, dispatch_typed_events,
-    do_remove_status, periodic_damage, status_debuff_multiplier, tick_statuses, timeout_statuses,
+    ActiveStatuses, ApplyStatus, PendingStatus, RemoveStatus, StatusResisted, TryApplyStatus,
+    TryRemoveStatus, apply_status_effects, dispatch_typed_events, dispatch_typed_removals,
+    do_remove_status, periodic_damage, run_apply_hooks, run_remove_hooks, run_tick_hooks,
+    status_debuff_multiplier, sync_active_statuses, tick_statuses, timeout_statuses,
 };
-use display::{add_status_animation, animate_status_effect, remove_status_animation_on_timeout};
-use fire::ignite_when_burned;
-use ice::freeze_when_wet;
-use std::time::Duration;
+use display::{
+    add_status_animation, draw_status_indicators, layout_status_icons,
+    remove_status_animation_on_timeout,
+};
+use flame::cook_after_burn;
+use reactions::evaluate_reactions;
 use tesla::{damage_after_electrocute, electrocute_on_damage};
 
 use crate::{
@@ -21,24 +24,39 @@ use crate::{
         projectiles::DamageType,
         stats::{DamageMultiplier, DamageMultiplierAll, MoveSpeed, StatFriction},
         status_effects::{
-            Acidified, Burned, Chilled, Electrocuted, Frozen, Ignited, Oiled, StatusEffect,
-            StatusEffectTrait, Wet,
+            Acidified, Burned, Chilled, Confused, Cooked, Discharge, Electrocuted, Frozen, Ignited,
+            KnockedBack, Oiled, Rooted, StatusEffect, StatusEffectTrait, Stunned, Wet,
         },
     },
     screens::Screen,
 };
 
-use super::stats::StatSet;
+use super::{boss::cleanse_boss_immunities, stats::StatSet};
 
 pub mod common;
 pub mod display;
-pub mod fire;
-pub mod ice;
+pub mod flame;
+pub mod reactions;
 pub mod tesla;
 
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<TryApplyStatus>()
-        .add_event::<TryApplyStatus>();
+        .register_type::<StatusResisted>()
+        .register_type::<ActiveStatuses>()
+        .add_event::<TryApplyStatus>()
+        .add_event::<PendingStatus>()
+        .add_event::<TryRemoveStatus>()
+        .add_event::<StatusResisted>()
+        .add_event::<Discharge>();
+
+    app.add_systems(
+        Update,
+        evaluate_reactions
+            .in_set(StatusSystems::React)
+            .in_set(PausableSystems)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    app.configure_sets(Update, (StatusSystems::React, StatusSystems::Apply).chain());
 
     app.add_systems(
         FixedUpdate,
@@ -52,11 +70,13 @@ pub(super) fn plugin(app: &mut App) {
             .run_if(in_state(Screen::Gameplay)),
     );
 
-    app.add_observer(freeze_when_wet);
-    app.add_observer(ignite_when_burned);
     app.add_systems(
         Update,
-        (damage_after_electrocute, electrocute_on_damage)
+        (
+            damage_after_electrocute,
+            electrocute_on_damage,
+            cook_after_burn,
+        )
             .in_set(PausableSystems)
             .run_if(in_state(Screen::Gameplay)),
     );
@@ -72,27 +92,54 @@ pub(super) fn plugin(app: &mut App) {
             status_debuff_multiplier::<Wet, DamageMultiplier<{ DamageType::Lightning }>>(1.5),
             status_debuff_multiplier::<Oiled, StatFriction>(0.),
             status_debuff_multiplier::<Burned, DamageMultiplierAll>(1.10),
-            status_debuff_multiplier::<Burned, DamageMultiplier<{ DamageType::Cold }>>(1.35),
+            status_debuff_multiplier::<Cooked, DamageMultiplier<{ DamageType::Cold }>>(1.35),
             status_debuff_multiplier::<Acidified, DamageMultiplierAll>(1.05),
             status_debuff_multiplier::<Acidified, DamageMultiplier<{ DamageType::Burning }>>(1.10),
             status_debuff_multiplier::<Acidified, DamageMultiplier<{ DamageType::Chemical }>>(1.10),
+            status_debuff_multiplier::<Stunned, MoveSpeed>(0.),
+            status_debuff_multiplier::<Stunned, StatFriction>(5.),
+            status_debuff_multiplier::<Rooted, MoveSpeed>(0.),
         )
             .in_set(StatSet::Modify)
             .in_set(PausableSystems)
             .run_if(in_state(Screen::Gameplay)),
     );
 
-    implement_status_effect::<Wet>(app);
-    implement_status_effect::<Ignited>(app);
-    implement_status_effect::<Burned>(app);
-    implement_status_effect::<Chilled>(app);
-    implement_status_effect::<Frozen>(app);
-    implement_status_effect::<Electrocuted>(app);
-    implement_status_effect::<Acidified>(app);
-    implement_status_effect::<Oiled>(app);
+    app.register_type::<display::StatusIcon>();
+    app.add_systems(
+        Update,
+        (layout_status_icons, draw_status_indicators)
+            .chain()
+            .after(StatusSystems::Apply)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+
+    implement_status::<Wet>(app);
+    implement_status::<Ignited>(app);
+    implement_status::<Burned>(app);
+    implement_status::<Chilled>(app);
+    implement_status::<Frozen>(app);
+    implement_status::<Electrocuted>(app);
+    implement_status::<Acidified>(app);
+    implement_status::<Oiled>(app);
+    implement_status::<Stunned>(app);
+    implement_status::<Rooted>(app);
+    implement_status::<Confused>(app);
+    implement_status::<KnockedBack>(app);
+    implement_status::<Cooked>(app);
 }
 
-pub fn implement_status_effect<T: StatusEffectTrait>(app: &mut App) {
+#[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum StatusSystems {
+    /// Incoming statuses and damage are checked against the reaction table
+    React,
+    /// Typed status events are dispatched, applied and removed
+    Apply,
+}
+
+/// Registers everything a status effect needs: its types and events, the systems that apply,
+/// tick and remove it, its lifecycle hooks and its sprite on the enemy
+pub fn implement_status<T: StatusEffectTrait>(app: &mut App) {
     app.register_type::<StatusEffect<T>>()
         .register_type::<T>()
         .add_event::<ApplyStatus<T>>()
@@ -100,22 +147,33 @@ pub fn implement_status_effect<T: StatusEffectTrait>(app: &mut App) {
         .add_systems(
             Update,
             (
-                dispatch_typed_events::<T>,
+                (
+                    dispatch_typed_events::<T>,
+                    dispatch_typed_removals::<T>,
+                    cleanse_boss_immunities::<T>,
+                    tick_statuses::<T>,
+                    timeout_statuses::<T>,
+                ),
+                (
+                    do_remove_status::<T>,
+                    run_remove_hooks::<T>,
+                    remove_status_animation_on_timeout::<T>,
+                ),
                 apply_status_effects::<T>,
-                tick_statuses::<T>,
-                timeout_statuses::<T>,
-                do_remove_status::<T>,
-                remove_status_animation_on_timeout::<T>,
+                (
+                    sync_active_statuses::<T>,
+                    run_apply_hooks::<T>,
+                    run_tick_hooks::<T>,
+                ),
             )
+                .chain()
+                .in_set(StatusSystems::Apply)
                 .in_set(PausableSystems)
                 .run_if(in_state(Screen::Gameplay)),
         )
         .add_systems(
             FixedUpdate,
-            (
-                (add_status_animation::<T>),
-                (animate_status_effect::<T>).run_if(on_timer(Duration::from_secs_f32(0.25))),
-            )
+            add_status_animation::<T>
                 .in_set(PausableSystems)
                 .run_if(in_state(Screen::Gameplay)),
         );
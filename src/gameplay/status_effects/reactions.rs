@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+use bevy_turborand::{DelegatedRng, GlobalRng};
+
+use super::common::{ActiveStatuses, PendingStatus, TryApplyStatus, TryRemoveStatus};
+use crate::{
+    data::reactions::{Reaction, ReactionTable, ReactionTrigger},
+    demo::enemy_health::TryDamageToEnemy,
+    gameplay::towers::explosions::Explode,
+};
+
+/// The only reader of `TryApplyStatus`. Every incoming status or damage hit is checked against
+/// the reaction table, and statuses that survive are passed on to be applied.
+pub fn evaluate_reactions(
+    mut status_attempts: EventReader<TryApplyStatus>,
+    mut damage_attempts: EventReader<TryDamageToEnemy>,
+    mut pending: EventWriter<PendingStatus>,
+    mut removals: EventWriter<TryRemoveStatus>,
+    enemies: Query<&ActiveStatuses>,
+    positions: Query<&GlobalTransform>,
+    mut explosions: EventWriter<Explode>,
+    table: Res<ReactionTable>,
+    mut rng: ResMut<GlobalRng>,
+    mut commands: Commands,
+) {
+    for attempt in status_attempts.read() {
+        let reactions = enemies
+            .get(attempt.enemy)
+            .map(|active| {
+                react(
+                    &table,
+                    active,
+                    ReactionTrigger::Status(attempt.status),
+                    attempt.strength,
+                    &mut *rng,
+                )
+            })
+            .unwrap_or_default();
+
+        let mut consumed = false;
+        for (reaction, existing_strength) in reactions {
+            consumed |= reaction.consume_incoming;
+            resolve(
+                reaction,
+                attempt.enemy,
+                existing_strength,
+                attempt.strength,
+                &mut removals,
+                &mut explosions,
+                &positions,
+                &mut commands,
+            );
+        }
+
+        if !consumed {
+            pending.write(PendingStatus(*attempt));
+        }
+    }
+
+    for attempt in damage_attempts.read() {
+        let Ok(active) = enemies.get(attempt.enemy) else {
+            continue;
+        };
+        for (reaction, existing_strength) in react(
+            &table,
+            active,
+            ReactionTrigger::Damage(attempt.damage_type),
+            attempt.strength,
+            &mut *rng,
+        ) {
+            resolve(
+                reaction,
+                attempt.enemy,
+                existing_strength,
+                attempt.strength,
+                &mut removals,
+                &mut explosions,
+                &positions,
+                &mut commands,
+            );
+        }
+    }
+}
+
+/// Every reaction that goes off for this hit, paired with the strength of the status it reacted
+/// with
+fn react<'a>(
+    table: &'a ReactionTable,
+    active: &ActiveStatuses,
+    incoming: ReactionTrigger,
+    incoming_strength: usize,
+    rng: &mut GlobalRng,
+) -> Vec<(&'a Reaction, usize)> {
+    active
+        .iter()
+        .flat_map(|(existing, strength)| {
+            table
+                .matching(existing, incoming)
+                .map(move |reaction| (reaction, strength))
+        })
+        .filter(|(reaction, strength)| rng.f32() < (reaction.chance)(*strength, incoming_strength))
+        .collect()
+}
+
+fn resolve(
+    reaction: &Reaction,
+    enemy: Entity,
+    existing_strength: usize,
+    incoming_strength: usize,
+    removals: &mut EventWriter<TryRemoveStatus>,
+    explosions: &mut EventWriter<Explode>,
+    positions: &Query<&GlobalTransform>,
+    commands: &mut Commands,
+) {
+    debug!(reaction = reaction.name, ?enemy, "Reaction");
+
+    if reaction.consume_existing {
+        removals.write(TryRemoveStatus {
+            status: reaction.existing,
+            enemy,
+        });
+    }
+
+    if let Some(product) = reaction.product {
+        let strength = (reaction.strength)(existing_strength, incoming_strength);
+        if strength > 0 {
+            // Products go back through the table, so reactions can chain
+            commands.send_event(TryApplyStatus {
+                status: product,
+                enemy,
+                strength,
+            });
+        }
+    }
+
+    if let Some((damage_type, damage)) = reaction.burst {
+        commands.send_event(TryDamageToEnemy {
+            damage,
+            strength: existing_strength.max(incoming_strength),
+            damage_type,
+            enemy,
+            source: None,
+        });
+    }
+
+    if let Some(explosion) = &reaction.explosion {
+        let Ok(transform) = positions.get(enemy) else {
+            return;
+        };
+        let strength = existing_strength.max(incoming_strength);
+        let center = transform.translation().xy();
+        if let Some(explosion) = Explode::from_spec(explosion, center, strength, enemy) {
+            explosions.write(explosion);
+        }
+    }
+}
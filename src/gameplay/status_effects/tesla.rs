@@ -9,20 +9,19 @@ use bevy::{
 };
 use bevy_turborand::{DelegatedRng, GlobalRng};
 
-use super::common::{RemoveStatus, TryApplyStatus};
+use super::common::TryApplyStatus;
 use crate::{
     data::{
-        StatusEffect,
         projectiles::DamageType,
-        status_effects::{Electrocuted, StatusEnum, Wet, damage_multiplier},
+        status_effects::{Discharge, StatusEnum, damage_multiplier},
     },
-    demo::enemy_health::{DoDamageToEnemy, EnemyHealth, TryDamageToEnemy},
+    demo::enemy_health::{EnemyHealth, TryDamageToEnemy},
 };
 
+/// Any lightning hit has a chance to shock. Wet enemies get an extra roll from the reaction table.
 pub fn electrocute_on_damage(
     mut damage_events: EventReader<TryDamageToEnemy>,
     mut status_events: EventWriter<TryApplyStatus>,
-    wet_enemies: Query<&StatusEffect<Wet>>,
     mut rng: ResMut<GlobalRng>,
 ) {
     for TryDamageToEnemy {
@@ -30,17 +29,16 @@ pub fn electrocute_on_damage(
         damage_type,
         enemy,
         strength,
+        ..
     } in damage_events.read()
     {
         if *damage_type == DamageType::Lightning {
-            let wetness_boost = wet_enemies.get(*enemy).map(|w| w.strength).unwrap_or(0);
-            let strength = strength + wetness_boost;
-            let shock_chance = damage_multiplier(strength + wetness_boost) * 0.1;
+            let shock_chance = damage_multiplier(*strength) * 0.1;
             if rng.f32() < shock_chance {
                 status_events.write(TryApplyStatus {
                     status: StatusEnum::Electrocuted,
                     enemy: *enemy,
-                    strength: 1.max(strength - 1),
+                    strength: 1.max(strength.saturating_sub(1)),
                 });
             }
         }
@@ -48,14 +46,11 @@ pub fn electrocute_on_damage(
 }
 
 pub fn damage_after_electrocute(
-    mut electro_events: EventReader<RemoveStatus<Electrocuted>>,
+    mut electro_events: EventReader<Discharge>,
     mut damage_events: EventWriter<TryDamageToEnemy>,
     enemies: Query<(Entity, &Transform), With<EnemyHealth>>,
 ) {
-    for RemoveStatus {
-        enemy, strength, ..
-    } in electro_events.read()
-    {
+    for Discharge { enemy, strength } in electro_events.read() {
         if let Ok((_, source_pos)) = enemies.get(*enemy) {
             let source_loc = source_pos.translation.clone();
             for (near_enemy, _) in enemies
@@ -67,6 +62,7 @@ pub fn damage_after_electrocute(
                     strength: *strength,
                     damage_type: DamageType::Lightning,
                     enemy: near_enemy,
+                    source: Some(*enemy),
                 });
                 println!("ZANG");
             }
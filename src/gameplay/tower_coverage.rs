@@ -0,0 +1,77 @@
+use crate::data::Tower;
+use crate::data::stats::{Stat, TriggerRange};
+use crate::gameplay::tower_inspection::HoveredTower;
+use crate::gameplay::towers::directional::FireDirection;
+use crate::gameplay::towers::support::TowerCell;
+use crate::level::resource::{CellDirection, Level};
+use crate::prelude::*;
+use bevy::color::palettes::css;
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+
+const TOGGLE_KEY: KeyCode = KeyCode::KeyC;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ShowTowerCoverage>();
+    app.add_systems(
+        Update,
+        toggle_tower_coverage
+            .run_if(in_state(Screen::Gameplay).and(input_just_pressed(TOGGLE_KEY))),
+    );
+    app.add_systems(
+        Update,
+        draw_tower_coverage.run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Draws the area of every tower on the map, rather than just the hovered one
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShowTowerCoverage(pub bool);
+
+fn toggle_tower_coverage(mut show: ResMut<ShowTowerCoverage>) {
+    show.0 = !show.0;
+}
+
+fn draw_tower_coverage(
+    show: Res<ShowTowerCoverage>,
+    hovered: Res<HoveredTower>,
+    level: Res<Level>,
+    towers: Query<(
+        Entity,
+        &Tower,
+        &TowerCell,
+        &CellDirection,
+        Option<&FireDirection>,
+        &Stat<TriggerRange>,
+    )>,
+    mut gizmos: Gizmos,
+) {
+    for (e, tower, TowerCell(cell), direction, fire_direction, range) in towers.iter() {
+        let is_hovered = hovered.0 == Some(e);
+        if !show.0 && !is_hovered {
+            continue;
+        }
+        // Towers face away from whatever they're mounted on unless told otherwise
+        let facing = fire_direction.map(|d| d.0).unwrap_or(direction.flip());
+        let Some(rect) = tower
+            .area()
+            .rect(*cell, facing, range.current_value(), &level)
+        else {
+            continue;
+        };
+        let color = if is_hovered {
+            css::GREEN.with_alpha(0.9)
+        } else {
+            css::LIGHT_SKY_BLUE.with_alpha(0.5)
+        };
+        draw_coverage(&mut gizmos, rect, color.into());
+    }
+}
+
+pub fn draw_coverage(gizmos: &mut Gizmos, rect: Rect, color: Color) {
+    gizmos.rect_2d(
+        Isometry2d::from_translation(rect.center()),
+        rect.size(),
+        color,
+    );
+}
@@ -0,0 +1,171 @@
+use crate::data::Tower;
+use crate::data::stats::{
+    ExtraShots, Stat, StatTrait, StatusStrength, TowerCooldown, TowerDamage, TriggerRange,
+};
+use crate::gameplay::stats::StatSet;
+use crate::gameplay::towers::support::TowerCell;
+use crate::level::components::LEVEL_SCALING;
+use crate::prelude::*;
+use crate::theme::widget;
+use bevy::color::palettes::tailwind;
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<HoveredTower>();
+    app.add_systems(OnEnter(Screen::Gameplay), on_enter_game);
+    app.add_systems(OnExit(Screen::Gameplay), clear_hovered_tower);
+    app.add_systems(
+        Update,
+        (
+            update_inspection_panel.after(StatSet::Recalc),
+            draw_aura_outlines,
+        )
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_observer(hover_tower);
+    app.add_observer(unhover_tower);
+}
+
+/// The tower under the cursor, if any
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HoveredTower(pub Option<Entity>);
+
+#[derive(Component)]
+struct InspectionPanel;
+
+#[derive(Component)]
+enum InspectionText {
+    Name,
+    Stats,
+}
+
+fn on_enter_game(mut commands: Commands) {
+    commands.spawn((
+        StateScoped(Screen::Gameplay),
+        Name::new("Tower Inspection"),
+        InspectionPanel,
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        BackgroundColor(tailwind::INDIGO_300.into()),
+        BorderColor(tailwind::INDIGO_100.into()),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(64.0),
+            right: Val::Px(16.0),
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            border: UiRect::all(Val::Px(4.0)),
+            ..default()
+        },
+        children![
+            (widget::ui_font("TOWER"), InspectionText::Name),
+            (widget::ui_font(""), InspectionText::Stats),
+        ],
+    ));
+}
+
+fn clear_hovered_tower(mut hovered: ResMut<HoveredTower>) {
+    hovered.0 = None;
+}
+
+fn hover_tower(
+    trigger: Trigger<Pointer<Over>>,
+    towers: Query<(), With<Tower>>,
+    mut hovered: ResMut<HoveredTower>,
+) {
+    if towers.contains(trigger.target()) {
+        hovered.0 = Some(trigger.target());
+    }
+}
+
+fn unhover_tower(trigger: Trigger<Pointer<Out>>, mut hovered: ResMut<HoveredTower>) {
+    if hovered.0 == Some(trigger.target()) {
+        hovered.0 = None;
+    }
+}
+
+fn update_inspection_panel(
+    hovered: Res<HoveredTower>,
+    towers: Query<(
+        &Tower,
+        &Stat<TowerCooldown>,
+        &Stat<TowerDamage>,
+        &Stat<TriggerRange>,
+        &Stat<StatusStrength>,
+        &Stat<ExtraShots>,
+    )>,
+    mut panels: Query<&mut Visibility, With<InspectionPanel>>,
+    mut texts: Query<(&mut Text, &InspectionText)>,
+) {
+    let Ok(mut visibility) = panels.single_mut() else {
+        return;
+    };
+    let Some((tower, cooldown, damage, range, status_strength, extra_shots)) =
+        hovered.0.and_then(|e| towers.get(e).ok())
+    else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    *visibility = Visibility::Inherited;
+
+    for (mut text, kind) in texts.iter_mut() {
+        text.0 = match kind {
+            InspectionText::Name => tower.name().to_uppercase(),
+            InspectionText::Stats => [
+                stat_breakdown(cooldown),
+                stat_breakdown(damage),
+                stat_breakdown(range),
+                stat_breakdown(status_strength),
+                stat_breakdown(extra_shots),
+            ]
+            .join("\n"),
+        };
+    }
+}
+
+/// "Cooldown: 2.25 (3.00 x0.75)", with the modifiers left out when there aren't any
+fn stat_breakdown<T: StatTrait>(stat: &Stat<T>) -> String {
+    let (pre_flat, multipliers, post_flat) = stat.modifiers();
+    let mut line = format!("{}: {:.2}", T::name(), stat.current_value());
+    if pre_flat.is_empty() && multipliers.is_empty() && post_flat.is_empty() {
+        return line;
+    }
+
+    line += &format!(" ({:.2}", stat.base());
+    for bonus in pre_flat {
+        line += &format!(" {bonus:+.2}");
+    }
+    for multiplier in multipliers {
+        line += &format!(" x{multiplier:.2}");
+    }
+    for bonus in post_flat {
+        line += &format!(" {bonus:+.2}");
+    }
+    line + ")"
+}
+
+fn draw_aura_outlines(
+    hovered: Res<HoveredTower>,
+    towers: Query<(&Tower, &TowerCell)>,
+    mut gizmos: Gizmos,
+) {
+    let Some((tower, TowerCell(center))) = hovered.0.and_then(|e| towers.get(e).ok()) else {
+        return;
+    };
+    let Some(aura) = tower.aura() else {
+        return;
+    };
+    let color = tower.sprite_tint().unwrap_or(Color::WHITE);
+    for x in -aura.radius..=aura.radius {
+        for y in -aura.radius..=aura.radius {
+            let cell = *center + IVec2::new(x, y);
+            gizmos.rect_2d(
+                Isometry2d::from_translation(cell.as_vec2() * LEVEL_SCALING),
+                Vec2::splat(LEVEL_SCALING),
+                color,
+            );
+        }
+    }
+}
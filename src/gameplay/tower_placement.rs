@@ -1,25 +1,32 @@
+use crate::theme::widget;
 use crate::{
     assets::{SoundEffects, TowerSprites},
     audio::sound_effect,
-    data::*,
-    gameplay::messages::DisplayFlashMessage,
+    data::{
+        levels::LevelData,
+        placement::{PlacementWorld, can_place},
+        *,
+    },
+    gameplay::{
+        grid_cursor::{ConfirmPlacement, GridCursor, is_placing},
+        messages::DisplayFlashMessage,
+        tower_coverage::draw_coverage,
+        towers::fan::fan_direction,
+    },
     level::{
-        components::{Adjacent, Ceiling, ExactPosition, Floor, LEVEL_SCALING, Wall, WallDirection},
-        resource::CellDirection,
+        components::{Adjacent, PathNode},
+        resource::{CellDirection, CurrentLoadedLevel, Level},
     },
     prelude::*,
 };
-use bevy::color::palettes::css;
+use bevy::color::palettes::{css, tailwind};
 use bevy::prelude::*;
 use bevy_composable::app_impl::{ComplexSpawnable, ComponentTreeable};
-use std::time::Duration;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_event::<TowerPlacementEvent>();
     app.add_event::<SelectTower>();
 
-    app.add_observer(on_turret_placement_hover);
-
     app.add_systems(
         Update,
         tower_placement_change.run_if(on_event::<TowerPlacementEvent>),
@@ -36,11 +43,23 @@ pub(super) fn plugin(app: &mut App) {
     );
 
     app.init_resource::<TowerPreview>();
-    app.init_resource::<BodgeTimer>();
-    app.add_systems(Update, (remove_preview, tick_spawn_timer));
-    app.add_observer(spawn_tower_on_click);
-    app.add_observer(right_click_tower_options);
-    app.add_observer(set_spawned_preview_sprite);
+    app.add_systems(
+        Update,
+        (
+            preview_at_cursor
+                .run_if(is_placing)
+                .before(tower_placement_change),
+            confirm_placement.run_if(on_event::<ConfirmPlacement>),
+        )
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_observer(confirm_on_click);
+
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_placement_tooltip);
+    app.add_systems(
+        Update,
+        (draw_preview_coverage, show_placement_validity).run_if(in_state(Screen::Gameplay)),
+    );
 
     app.add_systems(
         Update,
@@ -51,7 +70,7 @@ pub(super) fn plugin(app: &mut App) {
 }
 
 #[derive(Event, Debug, Hash, PartialEq, Eq, Clone, Reflect)]
-enum TowerPlacementEvent {
+pub enum TowerPlacementEvent {
     Requested(Tower, Entity, CellDirection),
     Accepted(Tower, Entity, CellDirection),
 }
@@ -66,15 +85,104 @@ struct TowerPreview {
 #[derive(Component, Debug, Default, Clone, Copy, Reflect)]
 struct SpawnedPreview;
 
-fn set_spawned_preview_sprite(
-    trigger: Trigger<OnAdd, SpawnedPreview>,
-    mut sprites: Query<&mut Sprite, With<SpawnedPreview>>,
+// Follows the cursor explaining why the previewed tower can't be placed
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+struct PlacementTooltip;
+
+fn spawn_placement_tooltip(mut commands: Commands) {
+    commands.spawn((
+        StateScoped(Screen::Gameplay),
+        Name::new("Placement Tooltip"),
+        PlacementTooltip,
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        BackgroundColor(tailwind::SLATE_100.into()),
+        Node {
+            position_type: PositionType::Absolute,
+            padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+            ..default()
+        },
+        children![widget::ui_font("")],
+    ));
+}
+
+/// Tints the preview green or red, and shows the reason next to the cursor when it's red
+fn show_placement_validity(
+    preview: Res<TowerPreview>,
+    player_state: Res<PlayerState>,
+    level_data: Res<LevelData>,
+    level: Res<CurrentLoadedLevel>,
+    towers: Query<(&ChildOf, &Tower)>,
+    adjacent_placements: Query<&Adjacent>,
+    windows: Query<&Window>,
+    mut previews: Query<&mut Sprite, With<SpawnedPreview>>,
+    mut tooltips: Query<(&mut Node, &mut Visibility, &Children), With<PlacementTooltip>>,
+    mut texts: Query<&mut Text>,
 ) {
-    let Ok(mut sprite) = sprites.get_mut(trigger.target()) else {
+    let Ok((mut node, mut visibility, children)) = tooltips.single_mut() else {
         return;
     };
+    let (Some(tower), Some(entity)) = (preview.tower, preview.position_entity) else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    let Ok(target) = adjacent_placements.get(entity) else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    let world = placement_world(
+        &player_state,
+        level_data.tower_limits(level.0),
+        &towers,
+        &adjacent_placements,
+    );
+    let result = can_place(tower, target, &world);
+
+    let color = match result {
+        Ok(()) => css::GREEN.with_alpha(0.65),
+        Err(_) => css::RED.with_alpha(0.65),
+    };
+    for mut sprite in previews.iter_mut() {
+        sprite.color = color.into();
+    }
 
-    sprite.color = css::GREEN.with_alpha(0.65).into();
+    let (Err(reason), Some(cursor)) = (
+        result,
+        windows.single().ok().and_then(|w| w.cursor_position()),
+    ) else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    *visibility = Visibility::Inherited;
+    node.left = Val::Px(cursor.x + 16.0);
+    node.top = Val::Px(cursor.y + 16.0);
+    for child in children.iter() {
+        if let Ok(mut text) = texts.get_mut(child) {
+            text.0 = reason.to_string();
+        }
+    }
+}
+
+pub fn placement_world(
+    player_state: &PlayerState,
+    level_limits: &[(Tower, usize)],
+    towers: &Query<(&ChildOf, &Tower)>,
+    adjacent_placements: &Query<&Adjacent>,
+) -> PlacementWorld {
+    PlacementWorld {
+        level_limits: level_limits.to_vec(),
+        money: player_state.money,
+        towers: towers
+            .iter()
+            .filter_map(|(parent, tower)| {
+                adjacent_placements
+                    .get(parent.0)
+                    .ok()
+                    .map(|adjacent| (*tower, *adjacent))
+            })
+            .collect(),
+    }
 }
 
 impl TowerPreview {
@@ -86,55 +194,35 @@ impl TowerPreview {
 #[derive(Event, Debug, Clone, Copy, Reflect)]
 struct SelectTower(pub Entity);
 
-#[derive(Debug, Clone, Reflect, Resource)]
-struct BodgeTimer(pub Timer);
-
-impl Default for BodgeTimer {
-    fn default() -> Self {
-        Self({
-            let mut temp = Timer::from_seconds(0.1, TimerMode::Once);
-            temp.set_elapsed(Duration::from_secs(100));
-            temp
-        })
-    }
-}
-
-fn remove_preview(
-    windows: Query<&Window>,
-    spawned_previews: Query<(Entity, &GlobalTransform), With<SpawnedPreview>>,
-    cameras: Query<(&Camera, &GlobalTransform)>,
-    mut preview: ResMut<TowerPreview>,
-    mut commands: Commands,
+fn draw_preview_coverage(
+    preview: Res<TowerPreview>,
+    level: Res<Level>,
+    panels: Query<(&Adjacent, &GlobalTransform)>,
+    nodes: Query<(&Transform, &PathNode)>,
+    mut gizmos: Gizmos,
 ) {
-    let Ok(window) = windows.single() else {
+    let (Some(tower), Some(entity), Some(direction)) = (
+        preview.tower,
+        preview.position_entity,
+        preview.cell_direction,
+    ) else {
         return;
     };
-
-    let Ok((camera, camera_transform)) = cameras.single() else {
+    let Ok((adjacent, transform)) = panels.get(entity) else {
         return;
     };
 
-    let Some(window_cursor_position) = window.cursor_position() else {
-        return;
+    // Fans pick their direction once placed, so work out the one they'd pick here
+    let facing = match tower.area() {
+        TowerArea::LineToWall => fan_direction(transform.translation(), nodes.iter()),
+        _ => Some(direction.flip()),
     };
-
-    let Ok(game_cursor_position) =
-        camera.viewport_to_world_2d(camera_transform, window_cursor_position)
+    let Some(rect) =
+        facing.and_then(|facing| tower.area().rect(adjacent.cell(), facing, 1., &level))
     else {
         return;
     };
-
-    for (entity, global_transform) in spawned_previews {
-        if global_transform
-            .translation()
-            .xy()
-            .distance(game_cursor_position)
-            > LEVEL_SCALING
-        {
-            commands.entity(entity).despawn();
-            preview.reset();
-        }
-    }
+    draw_coverage(&mut gizmos, rect, css::GREEN.with_alpha(0.65).into());
 }
 
 fn on_exit_placement_state(
@@ -184,30 +272,71 @@ fn tower_placement_change(
     });
 }
 
-fn tick_spawn_timer(mut timer: ResMut<BodgeTimer>, time: Res<Time>) {
-    timer.0.tick(time.delta());
+/// Keeps the preview on whatever face the grid cursor is pointing at
+fn preview_at_cursor(
+    cursor: Res<GridCursor>,
+    pointer_state: Res<State<PointerInteractionState>>,
+    mut preview: ResMut<TowerPreview>,
+    panels: Query<(Entity, &Adjacent)>,
+    spawned_previews: Query<Entity, With<SpawnedPreview>>,
+    mut tower_placement_writer: EventWriter<TowerPlacementEvent>,
+    mut commands: Commands,
+) {
+    let PointerInteractionState::Placing(tower) = *pointer_state.get() else {
+        return;
+    };
+
+    let panel = panels
+        .iter()
+        .find(|(_, adjacent)| adjacent.cell() == cursor.cell && adjacent.face() == cursor.face)
+        .map(|(entity, _)| entity);
+
+    match panel {
+        Some(entity) if preview.position_entity != Some(entity) || preview.tower != Some(tower) => {
+            tower_placement_writer.write(TowerPlacementEvent::Requested(
+                tower,
+                entity,
+                cursor.face,
+            ));
+        }
+        Some(_) => {}
+        None => {
+            for entity in spawned_previews.iter() {
+                commands.entity(entity).despawn();
+            }
+            preview.reset();
+        }
+    }
 }
 
-fn spawn_tower_on_click(
-    _: Trigger<Pointer<Click>>,
+/// Clicking anywhere in the level places at the grid cursor, which already follows the mouse
+fn confirm_on_click(
+    trigger: Trigger<Pointer<Click>>,
+    ui_nodes: Query<(), With<Node>>,
+    mut confirms: EventWriter<ConfirmPlacement>,
+) {
+    // Clicks bubble up through the hierarchy, so only react to the entity that was clicked
+    if trigger.target() != trigger.target
+        || trigger.event().button != PointerButton::Primary
+        || ui_nodes.contains(trigger.target)
+    {
+        return;
+    }
+    confirms.write(ConfirmPlacement);
+}
+
+fn confirm_placement(
     mut commands: Commands,
     mut next_pointer_state: ResMut<NextState<PointerInteractionState>>,
     mut tower_placement_writer: EventWriter<TowerPlacementEvent>,
     mut player_state: ResMut<PlayerState>,
-    mut timer: ResMut<BodgeTimer>,
-    windows: Query<&Window>,
-    cameras: Query<(&Camera, &GlobalTransform)>,
-    spawned_previews: Query<&GlobalTransform, With<SpawnedPreview>>,
     input: Res<ButtonInput<KeyCode>>,
     preview: Res<TowerPreview>,
-    relationships: Query<&Children>,
+    level_data: Res<LevelData>,
+    level: Res<CurrentLoadedLevel>,
     towers: Query<(&ChildOf, &Tower)>,
-    adjacent_placements: Query<(Entity, &Adjacent)>,
+    adjacent_placements: Query<&Adjacent>,
 ) {
-    if !timer.0.finished() {
-        return;
-    }
-
     // must be in valid state
     let (Some(tower), Some(entity), Some(orientation)) = (
         preview.tower,
@@ -217,151 +346,29 @@ fn spawn_tower_on_click(
         return;
     };
 
-    let (Ok(window), Ok((camera, camera_transform))) = (windows.single(), cameras.single()) else {
-        return;
-    };
-
-    let Some(window_cursor_position) = window.cursor_position() else {
-        return;
-    };
-
-    let Ok(game_cursor_position) =
-        camera.viewport_to_world_2d(camera_transform, window_cursor_position)
-    else {
+    let Ok(target) = adjacent_placements.get(entity) else {
         return;
     };
-
-    let previews = spawned_previews
-        .iter()
-        .filter(|transform| transform.translation().xy().distance(game_cursor_position) < 5.0)
-        .collect::<Vec<_>>();
-
-    if previews.len() != 1 {
-        return;
-    }
-
-    // prevent placement if user cannot afford tower
-    if !player_state.can_afford(tower.price()) {
-        commands.trigger(DisplayFlashMessage::new("Insufficient funds"));
+    let world = placement_world(
+        &player_state,
+        level_data.tower_limits(level.0),
+        &towers,
+        &adjacent_placements,
+    );
+    if let Err(reason) = can_place(tower, target, &world) {
+        commands.trigger(DisplayFlashMessage::new(reason.to_string()));
         return;
     }
 
-    // prevent double placement
-    if let Ok(children) = relationships.get(entity) {
-        for child in children {
-            if let Ok(_) = towers.get(*child) {
-                commands.trigger(DisplayFlashMessage::new("There is already a tower here"));
-                return;
-            }
-        }
-    }
-
-    // Some towers have limits to where they can be placed
-    if tower.requires_adjecent_wall() {
-        if let Ok((_, occupied_adjacent)) = adjacent_placements.get(entity) {
-            if tower.requires_floor_placement()
-                && occupied_adjacent.exact_position != ExactPosition::Floor
-            {
-                commands.trigger(DisplayFlashMessage::new(
-                    "This tower must be placed on a floor panel",
-                ));
-                return;
-            } else if tower.requires_floor_placement() && occupied_adjacent.id.unit_y == 0 {
-                commands.trigger(DisplayFlashMessage::new(
-                    "This tower cannot be placed on the bottom",
-                ));
-                return;
-            }
-            for (other_entity, target_adjacent) in adjacent_placements {
-                if occupied_adjacent.id == target_adjacent.id {
-                    if let Ok(children) = relationships.get(other_entity) {
-                        for child in children {
-                            if let Ok(_) = towers.get(*child) {
-                                commands.trigger(DisplayFlashMessage::new(
-                                    "This tower requires both sides of the wall",
-                                ));
-                                return;
-                            }
-                        }
-                    }
-                }
-            }
-        };
-    }
-    // Some towers have limits on where they can be placed
-    for (parent, tower) in towers {
-        if tower.requires_adjecent_wall() {
-            if let Ok((_, occupied_adjacent)) = adjacent_placements.get(parent.0) {
-                for (target_e, target_adjacent) in adjacent_placements {
-                    if target_e == entity && occupied_adjacent.id == target_adjacent.id {
-                        commands.trigger(DisplayFlashMessage::new(
-                            "Cannot place this tower under a trap door",
-                        ));
-                        return;
-                    }
-                }
-            }
-        }
-    }
-
     // All good, subtract money and place tower
     player_state.money -= tower.price();
     tower_placement_writer.write(TowerPlacementEvent::Accepted(tower, entity, orientation));
-    timer.0.reset();
 
     if !input.pressed(KeyCode::ShiftLeft) && !input.pressed(KeyCode::ShiftRight) {
         next_pointer_state.set(PointerInteractionState::Selecting);
     }
 }
 
-fn on_turret_placement_hover(
-    trigger: Trigger<Pointer<Over>>,
-    walls: Query<&Wall>,
-    floors: Query<(), With<Floor>>,
-    ceilings: Query<(), With<Ceiling>>,
-    pointer_input_state: Res<State<PointerInteractionState>>,
-    previews: Res<TowerPreview>,
-    relationships: Query<&Children>,
-    mut tower_placement_writer: EventWriter<TowerPlacementEvent>,
-) {
-    let PointerInteractionState::Placing(tower) = *pointer_input_state.get() else {
-        return;
-    };
-
-    let entity = trigger.target;
-
-    if let Ok(children) = relationships.get(entity) {
-        for child in children {
-            if let Some(e) = previews.position_entity {
-                if e == *child {
-                    // Early return if the entity already has a preview
-                    return;
-                }
-            }
-        }
-    }
-
-    if let Ok(wall) = walls.get(entity) {
-        let placement = match wall.0 {
-            WallDirection::Left => CellDirection::Left,
-            WallDirection::Right => CellDirection::Right,
-        };
-        tower_placement_writer.write(TowerPlacementEvent::Requested(tower, entity, placement));
-    } else if ceilings.get(entity).is_ok() {
-        tower_placement_writer.write(TowerPlacementEvent::Requested(
-            tower,
-            entity,
-            CellDirection::Up,
-        ));
-    } else if floors.get(entity).is_ok() {
-        tower_placement_writer.write(TowerPlacementEvent::Requested(
-            tower,
-            entity,
-            CellDirection::Down,
-        ));
-    }
-}
-
 fn place_towers(mut place_events: EventReader<TowerPlacementEvent>, mut commands: Commands) {
     for event in place_events.read() {
         match *event {
@@ -379,58 +386,6 @@ fn place_towers(mut place_events: EventReader<TowerPlacementEvent>, mut commands
     }
 }
 
-fn right_click_tower_options(
-    triggers: Trigger<Pointer<Click>>,
-    windows: Query<&Window>,
-    cameras: Query<(&Camera, &GlobalTransform)>,
-    preview: Res<TowerPreview>,
-    mut player_state: ResMut<PlayerState>,
-    towers: Query<(Entity, &GlobalTransform, &Tower)>,
-    mut commands: Commands,
-) {
-    if triggers.event().button == PointerButton::Secondary {
-        let Ok(window) = windows.single() else {
-            return;
-        };
-
-        let Ok((camera, camera_transform)) = cameras.single() else {
-            return;
-        };
-
-        let Some(window_cursor_position) = window.cursor_position() else {
-            return;
-        };
-
-        let Ok(game_cursor_position) =
-            camera.viewport_to_world_2d(camera_transform, window_cursor_position)
-        else {
-            return;
-        };
-
-        info!(window_position=?window_cursor_position, game_position=?game_cursor_position, "Cusor Position on click");
-
-        let mut in_range = towers
-            .iter()
-            .filter(|(_, transform, _)| {
-                transform.translation().xy().distance(game_cursor_position) < 5.0
-            })
-            .collect::<Vec<_>>();
-
-        in_range.sort_by(|a, b| {
-            a.1.translation()
-                .xy()
-                .distance(game_cursor_position)
-                .partial_cmp(&b.1.translation().xy().distance(game_cursor_position))
-                .unwrap()
-        });
-
-        if let Some((entity, _, tower)) = in_range.into_iter().next() {
-            player_state.money += tower.price();
-            commands.entity(entity).despawn();
-        }
-    }
-}
-
 fn play_tower_placement_sound(
     sfx: Res<SoundEffects>,
     mut place_events: EventReader<TowerPlacementEvent>,
@@ -1,6 +1,7 @@
 use super::{
     common::{TowerFired, TowerTriggerRange},
     directional::FireDirection,
+    explosions::Explode,
     piston::Shove,
 };
 use crate::{
@@ -8,9 +9,11 @@ use crate::{
     data::{
         Tower,
         projectiles::{
-            AttackData, AttackSpecification, Droplet, LiquidType, Puddle, TowerAttackType,
+            AttackData, AttackSpecification, Droplet, LiquidType, ProjectileSpec, Puddle,
+            TowerAttackType,
         },
-        status_effects::damage_multiplier,
+        stats::{Stat, StatusStrength, TowerDamage},
+        status_effects::{StatusEffect, StatusEnum, Wet, damage_multiplier, empowered_strength},
     },
     demo::enemy_health::{EnemyHealth, TryDamageToEnemy},
     gameplay::{
@@ -27,6 +30,9 @@ use avian2d::prelude::{Collisions, LinearVelocity, OnCollisionStart, Sensor};
 use bevy::prelude::*;
 use bevy_composable::app_impl::ComplexSpawnable;
 
+/// Pushes at least this strong knock enemies off their feet
+pub const KNOCKBACK_THRESHOLD: f32 = 100.;
+
 #[derive(Event, Reflect, Debug, PartialEq, Clone)]
 pub struct ApplyAttackData {
     pub target: Entity,
@@ -40,10 +46,14 @@ pub struct AttackEnemiesInContact(pub Entity, pub Vec<AttackSpecification>);
 #[derive(Event, Reflect, Debug, PartialEq, Clone, Copy)]
 pub struct DropLiquid(pub Entity, pub LiquidType);
 
+#[derive(Event, Reflect, Debug, PartialEq, Clone)]
+pub struct FireProjectile(pub Entity, pub ProjectileSpec);
+
 pub fn do_tower_attacks(
     mut fire_events: EventReader<TowerFired>,
     mut contact_events: EventWriter<AttackEnemiesInContact>,
     mut drop_events: EventWriter<DropLiquid>,
+    mut projectile_events: EventWriter<FireProjectile>,
     mut detect_trap_door_events: EventWriter<DetectTrapDoor>,
     towers: Query<(&Tower, &Children, &GlobalTransform)>,
     ranges: Query<(), With<TowerTriggerRange>>,
@@ -69,9 +79,13 @@ pub fn do_tower_attacks(
             TowerAttackType::DropsLiquid(liquid_type) => {
                 drop_events.write(DropLiquid(event.0, liquid_type));
             }
+            TowerAttackType::Projectile(spec) => {
+                projectile_events.write(FireProjectile(event.0, spec));
+            }
             TowerAttackType::ModifiesSelf => {
                 detect_trap_door_events.write(DetectTrapDoor(event.0));
             }
+            TowerAttackType::Passive => {}
         }
     }
 }
@@ -81,6 +95,8 @@ pub fn dispatch_attack_effects(
     mut damage_events: EventWriter<TryDamageToEnemy>,
     mut status_events: EventWriter<TryApplyStatus>,
     mut shoves: EventWriter<Shove>,
+    mut explosions: EventWriter<Explode>,
+    wet: Query<(Has<StatusEffect<Wet>>, Option<&GlobalTransform>)>,
 ) {
     for ApplyAttackData {
         target,
@@ -88,17 +104,20 @@ pub fn dispatch_attack_effects(
         effect,
     } in attackeffect_events.read()
     {
+        let (is_wet, target_transform) = wet.get(*target).unwrap_or((false, None));
         match effect {
             AttackData::Damage {
                 dmg_type,
                 strength,
                 damage,
             } => {
+                let strength = empowered_strength(*dmg_type, *strength, is_wet);
                 damage_events.write(TryDamageToEnemy {
-                    damage: (*damage as f32 * damage_multiplier(*strength)) as isize,
+                    damage: (*damage as f32 * damage_multiplier(strength)) as isize,
                     damage_type: *dmg_type,
                     enemy: *target,
                     strength: 1,
+                    source: Some(*source),
                 });
             }
             AttackData::Push {
@@ -106,17 +125,39 @@ pub fn dispatch_attack_effects(
                 strength,
                 force,
             } => {
-                shoves.write(Shove(
-                    *target,
-                    *direction,
-                    force * damage_multiplier(*strength),
-                ));
+                let force = force * damage_multiplier(*strength);
+                shoves.write(Shove(*target, *direction, force));
+                if force >= KNOCKBACK_THRESHOLD {
+                    status_events.write(TryApplyStatus {
+                        status: StatusEnum::KnockedBack,
+                        enemy: *target,
+                        strength: *strength,
+                    });
+                }
             }
             AttackData::Status { status, strength } => {
                 status_events.write(TryApplyStatus {
                     status: *status,
                     enemy: *target,
+                    strength: empowered_strength(status.damage_element(), *strength, is_wet),
+                });
+            }
+            AttackData::Explosion {
+                radius,
+                falloff,
+                strength,
+                payload,
+            } => {
+                let Some(transform) = target_transform else {
+                    continue;
+                };
+                explosions.write(Explode {
+                    center: transform.translation().xy(),
+                    radius: *radius,
+                    falloff: *falloff,
                     strength: *strength,
+                    payload: payload.clone(),
+                    source: *source,
                 });
             }
         }
@@ -149,15 +190,18 @@ pub fn attack_contact_enemies(
     directions: Query<&FireDirection>,
     parents: Query<&ChildOf, With<TowerTriggerRange>>,
     enemies: Query<(), With<EnemyHealth>>,
-    towers: Query<(&Tower, &CellDirection, &AnimationFrameQueue)>,
+    tower_stats: Query<(&Stat<TowerDamage>, &Stat<StatusStrength>)>,
 ) {
     for &AttackEnemiesInContact(sensor, ref effects) in events.read() {
-        let direction = parents
-            .get(sensor)
-            .ok()
-            .map(|w| directions.get(w.0).ok().map(|w| w.0))
+        let tower = parents.get(sensor).ok().map(|w| w.0);
+        let direction = tower
+            .map(|w| directions.get(w).ok().map(|w| w.0))
             .flatten()
             .unwrap_or(CellDirection::Up);
+        let (damage, status_strength) = tower
+            .and_then(|w| tower_stats.get(w).ok())
+            .map(|(damage, status)| (damage.current_value(), status.current_value()))
+            .unwrap_or((1., 1.));
 
         let enemies: Vec<_> = collisions
             .entities_colliding_with(sensor)
@@ -169,22 +213,7 @@ pub fn attack_contact_enemies(
                 attack_events.write(ApplyAttackData {
                     target: *enemy,
                     source: sensor,
-                    effect: match effect {
-                        AttackSpecification::Damage(damage_type, damage) => AttackData::Damage {
-                            dmg_type: *damage_type,
-                            strength: 1,
-                            damage: *damage,
-                        },
-                        AttackSpecification::Push(force) => AttackData::Push {
-                            direction: direction,
-                            strength: 1,
-                            force: *force,
-                        },
-                        AttackSpecification::Status(status_enum) => AttackData::Status {
-                            status: *status_enum,
-                            strength: 1,
-                        },
-                    },
+                    effect: effect.to_tower_data(direction, damage, status_strength),
                 });
             }
         }
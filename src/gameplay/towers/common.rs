@@ -8,11 +8,19 @@ use bevy::{
         query::{Changed, With, Without},
         system::{Commands, Query, Res},
     },
+    math::Vec3,
     reflect::Reflect,
     time::{Time, Timer},
+    transform::components::Transform,
 };
 
-use crate::{data::Tower, demo::enemy_health::EnemyHealth};
+use crate::{
+    data::{
+        Tower, TowerArea,
+        stats::{ExtraShots, Stat, TowerCooldown, TriggerRange},
+    },
+    demo::enemy_health::EnemyHealth,
+};
 
 // Attached to sensor colliders that detect enemies for towers
 #[derive(Copy, Clone, Debug, Reflect, Component, PartialEq, Eq)]
@@ -33,6 +41,16 @@ pub struct TowerHasTargets;
 #[derive(Copy, Clone, Debug, Reflect, Event, PartialEq, Eq)]
 pub struct TowerFired(pub Entity);
 
+// Extra shots a tower still has to fire this cooldown. They're spaced out so they don't land
+// inside the invulnerability window left by the first shot.
+#[derive(Clone, Debug, Reflect, Component, PartialEq, Eq)]
+pub struct QueuedShots {
+    pub remaining: usize,
+    pub timer: Timer,
+}
+
+pub const QUEUED_SHOT_DELAY: f32 = 0.3;
+
 pub fn add_tower_targets_from_zone(
     mut collision_events: EventReader<CollisionStarted>,
     trigger_zones: Query<&ChildOf, With<TowerTriggerRange>>,
@@ -82,13 +100,42 @@ pub fn remove_tower_targets(
 }
 
 pub fn towers_fire(
-    towers: Query<(Entity, &Tower), (With<TowerHasTargets>, Without<Cooldown>)>,
+    towers: Query<
+        (Entity, &Stat<TowerCooldown>, &Stat<ExtraShots>),
+        (With<Tower>, With<TowerHasTargets>, Without<Cooldown>),
+    >,
+    mut fire_events: EventWriter<TowerFired>,
+    mut commands: Commands,
+) {
+    for (e, cooldown, extra_shots) in towers.iter() {
+        commands
+            .entity(e)
+            .insert(Cooldown::new(cooldown.current_value()));
+        fire_events.write(TowerFired(e));
+
+        let extra_shots = extra_shots.current_value().round() as usize;
+        if extra_shots > 0 {
+            commands.entity(e).insert(QueuedShots::new(extra_shots));
+        }
+    }
+}
+
+pub fn fire_queued_shots(
+    mut towers: Query<(Entity, &mut QueuedShots)>,
     mut fire_events: EventWriter<TowerFired>,
+    time: Res<Time>,
     mut commands: Commands,
 ) {
-    for (e, tower) in towers.iter() {
-        commands.entity(e).insert(Cooldown::new(tower.cooldown()));
+    for (e, mut queued) in towers.iter_mut() {
+        queued.timer.tick(time.delta());
+        if !queued.timer.just_finished() {
+            continue;
+        }
         fire_events.write(TowerFired(e));
+        queued.remaining = queued.remaining.saturating_sub(1);
+        if queued.remaining == 0 {
+            commands.entity(e).remove::<QueuedShots>();
+        }
     }
 }
 
@@ -114,3 +161,32 @@ impl Cooldown {
         Self(Timer::from_seconds(time_secs, bevy::time::TimerMode::Once))
     }
 }
+
+impl QueuedShots {
+    pub fn new(shots: usize) -> Self {
+        Self {
+            remaining: shots,
+            timer: Timer::from_seconds(QUEUED_SHOT_DELAY, bevy::time::TimerMode::Repeating),
+        }
+    }
+}
+
+/// Scales trigger zones by the tower's `TriggerRange`. Zones that have been dropped to the floor
+/// only grow sideways, since their height comes from the floor below them.
+pub fn resize_trigger_ranges(
+    towers: Query<(&Tower, &Stat<TriggerRange>, &Children), Changed<Stat<TriggerRange>>>,
+    mut ranges: Query<&mut Transform, With<TowerTriggerRange>>,
+) {
+    for (tower, range, children) in towers.iter() {
+        let scale = range.current_value();
+        for child in children.iter() {
+            if let Ok(mut transform) = ranges.get_mut(child) {
+                transform.scale = if tower.area() == TowerArea::ColumnBelow {
+                    Vec3::new(scale, 1., 1.)
+                } else {
+                    Vec3::new(scale, scale, 1.)
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use super::attacks::{ApplyAttackData, KNOCKBACK_THRESHOLD};
+use crate::{
+    data::{
+        projectiles::{AttackSpecification, explosion_scale},
+        status_effects::{StatusEffect, StatusEnum, Stunned},
+    },
+    demo::enemy_health::{DeathEffect, EnemyHealth, KillEnemy},
+    gameplay::status_effects::common::TryApplyStatus,
+    level::resource::CellDirection,
+    prefabs::physics::GamePhysicsLayer as GPL,
+};
+use avian2d::prelude::{Collider, ExternalImpulse, SpatialQuery, SpatialQueryFilter};
+use bevy::prelude::*;
+
+#[derive(Event, Reflect, Debug, PartialEq, Clone)]
+pub struct Explode {
+    pub center: Vec2,
+    pub radius: f32,
+    pub falloff: f32,
+    pub strength: usize,
+    pub payload: Vec<AttackSpecification>,
+    /// Whatever set the explosion off, used as the damage source
+    pub source: Entity,
+}
+
+impl Explode {
+    /// Builds an explosion out of an `AttackSpecification::Explosion`, or `None` for anything else
+    pub fn from_spec(
+        spec: &AttackSpecification,
+        center: Vec2,
+        strength: usize,
+        source: Entity,
+    ) -> Option<Self> {
+        match spec {
+            AttackSpecification::Explosion {
+                radius,
+                falloff,
+                payload,
+            } => Some(Self {
+                center,
+                radius: *radius,
+                falloff: *falloff,
+                strength,
+                payload: payload.clone(),
+                source,
+            }),
+            _ => None,
+        }
+    }
+}
+
+pub fn detonate_explosions(
+    mut explosions: EventReader<Explode>,
+    spatial_query: SpatialQuery,
+    mut enemies: Query<
+        (
+            &GlobalTransform,
+            Option<&mut ExternalImpulse>,
+            Has<StatusEffect<Stunned>>,
+        ),
+        With<EnemyHealth>,
+    >,
+    mut attack_events: EventWriter<ApplyAttackData>,
+    mut status_events: EventWriter<TryApplyStatus>,
+    mut commands: Commands,
+) {
+    for explosion in explosions.read() {
+        let caught = spatial_query.shape_intersections(
+            &Collider::circle(explosion.radius),
+            explosion.center,
+            0.,
+            &SpatialQueryFilter::from_mask(GPL::Enemy),
+        );
+
+        for enemy in caught {
+            let Ok((transform, mut impulse, stunned)) = enemies.get_mut(enemy) else {
+                continue;
+            };
+            let offset = transform.translation().xy() - explosion.center;
+            let scale = explosion_scale(offset.length(), explosion.radius, explosion.falloff);
+            let away = offset.try_normalize().unwrap_or(Vec2::Y);
+
+            for effect in explosion.payload.iter().filter_map(|w| w.scaled(scale)) {
+                match effect {
+                    AttackSpecification::Push(force) => {
+                        if let Some(impulse) = impulse.as_mut().filter(|_| !stunned) {
+                            ***impulse += away * force;
+                        }
+                        if force >= KNOCKBACK_THRESHOLD {
+                            status_events.write(TryApplyStatus {
+                                status: StatusEnum::KnockedBack,
+                                enemy,
+                                strength: explosion.strength,
+                            });
+                        }
+                    }
+                    AttackSpecification::Explosion { .. } => {
+                        if let Some(chained) = Explode::from_spec(
+                            &effect,
+                            transform.translation().xy(),
+                            explosion.strength,
+                            explosion.source,
+                        ) {
+                            commands.send_event(chained);
+                        }
+                    }
+                    _ => {
+                        attack_events.write(ApplyAttackData {
+                            target: enemy,
+                            source: explosion.source,
+                            effect: effect.to_data(CellDirection::Up, explosion.strength),
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn trigger_death_effects(
+    mut kills: EventReader<KillEnemy>,
+    enemies: Query<(&GlobalTransform, &DeathEffect)>,
+    mut explosions: EventWriter<Explode>,
+) {
+    for KillEnemy(enemy) in kills.read() {
+        let Ok((transform, DeathEffect(effects))) = enemies.get(*enemy) else {
+            continue;
+        };
+        for effect in effects {
+            if let Some(explosion) =
+                Explode::from_spec(effect, transform.translation().xy(), 1, *enemy)
+            {
+                explosions.write(explosion);
+            }
+        }
+    }
+}
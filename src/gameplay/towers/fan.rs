@@ -27,18 +27,9 @@ pub fn spawn_fancasters(
 
     for (e, transform) in fans.iter() {
         let (_, rotation, location) = transform.to_scale_rotation_translation();
-
-        let mut nodes_sorted_by_distance = nodes
-            .iter()
-            .map(|w| {
-                (
-                    location.distance(w.0.translation.xy().extend(0.)),
-                    (w.0, w.1.prev_direction),
-                )
-            })
-            .collect::<Vec<_>>();
-        nodes_sorted_by_distance.sort_by(|w, other| w.0.total_cmp(&other.0));
-        let direction = nodes_sorted_by_distance[0].1.1.flip();
+        let Some(direction) = fan_direction(location, nodes.iter()) else {
+            continue;
+        };
 
         let raycaster = RayCaster::new(Vec2::ZERO, Dir2::new_unchecked(direction.into()))
             .with_query_filter(avian2d::prelude::SpatialQueryFilter {
@@ -58,6 +49,22 @@ pub fn spawn_fancasters(
     }
 }
 
+/// Fans blow against the flow of the path node closest to them
+pub fn fan_direction<'a>(
+    location: Vec3,
+    nodes: impl Iterator<Item = (&'a Transform, &'a PathNode)>,
+) -> Option<CellDirection> {
+    nodes
+        .map(|(transform, node)| {
+            (
+                location.distance(transform.translation.xy().extend(0.)),
+                node.prev_direction,
+            )
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, prev_direction)| prev_direction.flip())
+}
+
 pub fn resolve_fancasters(
     casters: Query<(Entity, &RayHits, &FanCaster)>,
     fans: Query<&FireDirection>,
@@ -2,7 +2,7 @@ use super::attacks::{ApplyAttackData, DropLiquid};
 use crate::{
     data::{
         Tower,
-        projectiles::{AttackData, AttackSpecification, DamageType, Droplet, Puddle},
+        projectiles::{DamageType, Puddle, PuddleCondition, PuddleState},
     },
     demo::enemy_health::{EnemyHealth, TryDamageToEnemy},
     gameplay::{animation::AnimationFrameQueue, shared_systems::Lifetime},
@@ -10,9 +10,9 @@ use crate::{
         components::{Architecture, pos},
         resource::CellDirection,
     },
-    prefabs::attacks::{droplet, puddle},
+    prefabs::attacks::droplet,
 };
-use avian2d::prelude::{LinearVelocity, OnCollisionStart, Sensor};
+use avian2d::prelude::{LinearVelocity, OnCollisionStart};
 use bevy::prelude::*;
 use bevy_composable::app_impl::ComplexSpawnable;
 
@@ -38,52 +38,27 @@ pub fn drop_liquids(
     }
 }
 
-pub fn splat_droplets(
-    trigger: Trigger<OnCollisionStart>,
-    sensors: Query<(), With<Sensor>>,
-    droplets: Query<(&Transform, &Droplet)>,
-    mut commands: Commands,
-) {
-    let droplet = trigger.target();
-    let other = trigger.collider;
-
-    // We don't want droplets to do things when they hit sensors
-    if sensors.get(other).is_err() {
-        if let Ok((transform, Droplet(liquid))) = droplets.get(droplet) {
-            let loc = transform.translation;
-            commands.entity(droplet).despawn();
-            commands.compose(puddle(*liquid) + pos(loc.x, loc.y));
-        }
-    }
-}
-
 pub fn puddle_attacks(
     trigger: Trigger<OnCollisionStart>,
     enemies: Query<(), With<EnemyHealth>>,
-    puddles: Query<&Puddle>,
+    puddles: Query<(&Puddle, &PuddleCondition)>,
     mut attack_events: EventWriter<ApplyAttackData>,
 ) {
     let puddle = trigger.target();
     let other = trigger.collider;
 
     if enemies.get(other).is_ok() {
-        if let Ok(Puddle(liquid)) = puddles.get(puddle) {
+        if let Ok((Puddle(liquid), condition)) = puddles.get(puddle) {
+            // Ice is just ice
+            if condition.state == PuddleState::Frozen {
+                return;
+            }
             for effect in liquid.contact_effects() {
                 attack_events.write(ApplyAttackData {
                     target: other,
                     source: puddle,
-                    effect: match effect {
-                        AttackSpecification::Damage(damage_type, damage) => AttackData::Damage {
-                            dmg_type: damage_type,
-                            strength: 1,
-                            damage,
-                        },
-                        AttackSpecification::Push(_) => todo!(),
-                        AttackSpecification::Status(status_enum) => AttackData::Status {
-                            status: status_enum,
-                            strength: 1,
-                        },
-                    },
+                    // Puddles have nothing to push with, so anything they push goes up
+                    effect: effect.to_data(CellDirection::Up, 1),
                 });
             }
         }
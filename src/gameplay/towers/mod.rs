@@ -1,42 +1,62 @@
 use crate::{
     PausableSystems,
-    data::projectiles::{Droplet, Puddle},
+    data::projectiles::{
+        Droplet, Projectile, ProjectileHitbox, Puddle, PuddleCondition, PuddleVolume,
+    },
+    gameplay::stats::StatSet,
     prefabs::wizardry::add_observer_to_component,
     screens::Screen,
 };
 use attacks::{
-    ApplyAttackData, AttackEnemiesInContact, DropLiquid, animate_towers_on_attack,
+    ApplyAttackData, AttackEnemiesInContact, DropLiquid, FireProjectile, animate_towers_on_attack,
     attack_contact_enemies, dispatch_attack_effects, do_tower_attacks, play_tower_sfx,
 };
 use bevy::prelude::*;
 use common::*;
 use directional::FireDirection;
+use explosions::{Explode, detonate_explosions, trigger_death_effects};
 use fan::{ForceField, do_forcefields, resolve_fancasters, spawn_fancasters};
 use gravity_bullshit::{RangeDropper, drop_ranges, spawn_rangedroppers};
-use liquids::{drop_liquids, puddle_attacks, splat_droplets, stop_dropping_puddles};
+use liquids::{drop_liquids, puddle_attacks, stop_dropping_puddles};
 use piston::{Shove, do_shoves};
+use projectiles::{fire_projectiles, projectile_bounces, projectile_hits};
+use puddles::{
+    evaporate_puddles, scale_puddles_by_volume, set_off_puddles, slip_on_frozen_puddles,
+    splat_droplets, tick_puddle_hazards, tint_puddles,
+};
+use support::{TowerCell, apply_auras, assign_tower_cells};
 use trap_door::{DetectTrapDoor, OpenTrapDoor, close_trap_door, detect_trap_door, open_trap_door};
 
 pub mod attacks;
 pub mod common;
 pub mod directional;
+pub mod explosions;
 pub mod fan;
 pub mod gravity_bullshit;
 pub mod liquids;
 pub mod piston;
+pub mod projectiles;
+pub mod puddles;
+pub mod support;
 pub mod trap_door;
 
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<TowerTriggerRange>()
         .register_type::<TowerTriggerNeedsGravity>()
         .register_type::<Cooldown>()
+        .register_type::<QueuedShots>()
+        .register_type::<TowerCell>()
         .register_type::<TowerFired>()
         .register_type::<FireDirection>()
         .register_type::<ForceField>()
         .register_type::<TowerHasTargets>()
-        .register_type::<RangeDropper>();
+        .register_type::<RangeDropper>()
+        .register_type::<PuddleVolume>()
+        .register_type::<PuddleCondition>();
 
     app.add_event::<DropLiquid>()
+        .add_event::<FireProjectile>()
+        .add_event::<Explode>()
         .add_event::<TowerFired>()
         .add_event::<Shove>()
         .add_event::<ApplyAttackData>()
@@ -54,17 +74,25 @@ pub(super) fn plugin(app: &mut App) {
     app.add_observer(add_observer_to_component::<Droplet, _, _, _, _>(
         splat_droplets,
     ));
+    app.add_observer(add_observer_to_component::<Projectile, _, _, _, _>(
+        projectile_bounces,
+    ));
+    app.add_observer(add_observer_to_component::<ProjectileHitbox, _, _, _, _>(
+        projectile_hits,
+    ));
 
     app.add_systems(
         Update,
         (
             (tick_cooldown, remove_cooldown).chain(),
+            resize_trigger_ranges,
             (
-                towers_fire,
+                (towers_fire, fire_queued_shots),
                 do_tower_attacks,
                 (
                     attack_contact_enemies,
                     drop_liquids,
+                    fire_projectiles,
                     detect_trap_door,
                     open_trap_door,
                     close_trap_door,
@@ -76,8 +104,24 @@ pub(super) fn plugin(app: &mut App) {
                     animate_towers_on_attack,
                     play_tower_sfx,
                 ),
+                (trigger_death_effects, detonate_explosions).chain(),
             )
                 .chain(),
+        )
+            .in_set(StatSet::Use)
+            .in_set(PausableSystems)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+
+    app.add_systems(
+        Update,
+        (
+            (set_off_puddles, tick_puddle_hazards, tint_puddles).chain(),
+            (evaporate_puddles, scale_puddles_by_volume).chain(),
+            slip_on_frozen_puddles.in_set(StatSet::Modify),
+            (assign_tower_cells, apply_auras)
+                .chain()
+                .in_set(StatSet::Modify),
         )
             .in_set(PausableSystems)
             .run_if(in_state(Screen::Gameplay)),
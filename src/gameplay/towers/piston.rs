@@ -1,4 +1,8 @@
-use crate::{demo::enemy_health::EnemyHealth, level::resource::CellDirection};
+use crate::{
+    data::status_effects::{StatusEffect, Stunned},
+    demo::enemy_health::EnemyHealth,
+    level::resource::CellDirection,
+};
 use avian2d::prelude::ExternalImpulse;
 use bevy::prelude::*;
 
@@ -7,7 +11,7 @@ pub struct Shove(pub Entity, pub CellDirection, pub f32);
 
 pub fn do_shoves(
     mut events: EventReader<Shove>,
-    mut enemies: Query<&mut ExternalImpulse, With<EnemyHealth>>,
+    mut enemies: Query<&mut ExternalImpulse, (With<EnemyHealth>, Without<StatusEffect<Stunned>>)>,
 ) {
     for Shove(e, direction, power) in events.read() {
         if let Ok(mut impulse) = enemies.get_mut(*e) {
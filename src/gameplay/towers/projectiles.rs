@@ -0,0 +1,188 @@
+use super::{
+    attacks::{ApplyAttackData, FireProjectile},
+    common::TowerTriggerRange,
+    directional::FireDirection,
+    explosions::Explode,
+};
+use crate::{
+    data::{
+        Tower,
+        projectiles::{Projectile, ProjectileAim, ProjectileHitbox},
+        stats::{Stat, StatusStrength, TowerDamage},
+    },
+    demo::enemy_health::EnemyHealth,
+    gameplay::animation::AnimationFrameQueue,
+    level::{components::pos, resource::CellDirection},
+    prefabs::attacks::projectile,
+};
+use avian2d::prelude::{Collisions, Gravity, LinearVelocity, OnCollisionStart, Sensor};
+use bevy::prelude::*;
+use bevy_composable::{app_impl::ComplexSpawnable, app_impl::ComponentTreeable};
+
+pub fn fire_projectiles(
+    mut events: EventReader<FireProjectile>,
+    mut towers: Query<(
+        &Tower,
+        &GlobalTransform,
+        &CellDirection,
+        Option<&FireDirection>,
+        &Children,
+        &Stat<TowerDamage>,
+        &Stat<StatusStrength>,
+        &mut AnimationFrameQueue,
+    )>,
+    ranges: Query<(), With<TowerTriggerRange>>,
+    enemies: Query<&GlobalTransform, With<EnemyHealth>>,
+    collisions: Collisions,
+    gravity: Res<Gravity>,
+    mut commands: Commands,
+) {
+    for FireProjectile(e, spec) in events.read() {
+        let Ok((
+            tower,
+            global_transform,
+            cell_direction,
+            fire_direction,
+            children,
+            damage,
+            status_strength,
+            mut animation,
+        )) = towers.get_mut(*e)
+        else {
+            warn!("Tower not found in fire_projectiles");
+            continue;
+        };
+        let origin = global_transform.translation().xy();
+
+        let velocity = match spec.aim {
+            ProjectileAim::FireDirection => {
+                fire_direction.map(|w| w.0).unwrap_or(*cell_direction).vec() * spec.speed
+            }
+            ProjectileAim::NearestEnemy => {
+                let nearest = children
+                    .iter()
+                    .filter(|w| ranges.contains(*w))
+                    .flat_map(|range| collisions.entities_colliding_with(range))
+                    .filter_map(|other| enemies.get(other).ok())
+                    .map(|transform| transform.translation().xy())
+                    .min_by(|a, b| {
+                        a.distance_squared(origin)
+                            .total_cmp(&b.distance_squared(origin))
+                    });
+                let Some(target) = nearest else {
+                    continue;
+                };
+                lob_velocity(origin, target, spec.speed, gravity.0 * spec.gravity)
+            }
+        };
+
+        commands.compose(
+            projectile(
+                spec,
+                damage.current_value(),
+                status_strength.current_value(),
+            ) + pos(origin.x, origin.y)
+                + LinearVelocity(velocity).store(),
+        );
+        animation.set_override(cell_direction.attack_frames(tower));
+    }
+}
+
+pub fn projectile_hits(
+    trigger: Trigger<OnCollisionStart>,
+    hitboxes: Query<&ChildOf, With<ProjectileHitbox>>,
+    mut projectiles: Query<(&mut Projectile, &LinearVelocity)>,
+    enemies: Query<(), With<EnemyHealth>>,
+    mut attack_events: EventWriter<ApplyAttackData>,
+    mut commands: Commands,
+) {
+    let hitbox = trigger.target();
+    let enemy = trigger.collider;
+
+    if !enemies.contains(enemy) {
+        return;
+    }
+    let Ok(ChildOf(body)) = hitboxes.get(hitbox) else {
+        return;
+    };
+    let Ok((mut projectile, velocity)) = projectiles.get_mut(*body) else {
+        return;
+    };
+    if projectile.spent || projectile.already_hit.contains(&enemy) {
+        return;
+    }
+    projectile.already_hit.push(enemy);
+
+    let direction = direction_of_travel(velocity.0);
+    for effect in &projectile.payload {
+        attack_events.write(ApplyAttackData {
+            target: enemy,
+            source: *body,
+            effect: effect.to_tower_data(direction, 1., projectile.status_strength),
+        });
+    }
+
+    match projectile.pierce_left {
+        0 => {
+            projectile.spent = true;
+            commands.entity(*body).despawn();
+        }
+        _ => projectile.pierce_left -= 1,
+    }
+}
+
+pub fn projectile_bounces(
+    trigger: Trigger<OnCollisionStart>,
+    sensors: Query<(), With<Sensor>>,
+    mut projectiles: Query<(&mut Projectile, &GlobalTransform)>,
+    mut explosions: EventWriter<Explode>,
+    mut commands: Commands,
+) {
+    let body = trigger.target();
+    let other = trigger.collider;
+
+    if sensors.contains(other) {
+        return;
+    }
+    let Ok((mut projectile, transform)) = projectiles.get_mut(body) else {
+        return;
+    };
+    if projectile.spent {
+        return;
+    }
+    if projectile.bounces_left > 0 {
+        projectile.bounces_left -= 1;
+        return;
+    }
+
+    // Shells that land without hitting anyone still go off where they land
+    for effect in &projectile.payload {
+        if let Some(explosion) = Explode::from_spec(effect, transform.translation().xy(), 1, body) {
+            explosions.write(explosion);
+        }
+    }
+    projectile.spent = true;
+    commands.entity(body).despawn();
+}
+
+/// Launch velocity that carries a shot from `from` to `to` under `gravity`, taking roughly as
+/// long as a straight shot at `speed` would
+fn lob_velocity(from: Vec2, to: Vec2, speed: f32, gravity: Vec2) -> Vec2 {
+    let offset = to - from;
+    let flight_time = (offset.length() / speed).max(0.1);
+    offset / flight_time - gravity * flight_time * 0.5
+}
+
+fn direction_of_travel(velocity: Vec2) -> CellDirection {
+    if velocity.x.abs() > velocity.y.abs() {
+        if velocity.x > 0. {
+            CellDirection::Right
+        } else {
+            CellDirection::Left
+        }
+    } else if velocity.y > 0. {
+        CellDirection::Up
+    } else {
+        CellDirection::Down
+    }
+}
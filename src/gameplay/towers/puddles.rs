@@ -0,0 +1,268 @@
+use crate::{
+    assets::LiquidSprites,
+    data::{
+        StatusEffect,
+        projectiles::{
+            DROPLET_VOLUME, DamageType, Droplet, LiquidMix, LiquidType, MAX_PUDDLE_VOLUME, Puddle,
+            PuddleCondition, PuddleState, PuddleVolume, mix_liquids, puddle_reaction,
+        },
+        stats::{Stat, StatFriction},
+        status_effects::{Burned, Ignited, StatusEnum},
+    },
+    demo::enemy_health::{EnemyHealth, TryDamageToEnemy},
+    gameplay::status_effects::common::TryApplyStatus,
+    level::components::{LEVEL_SCALING, pos},
+    prefabs::attacks::puddle,
+};
+use avian2d::prelude::{Collisions, OnCollisionStart, Sensor};
+use bevy::prelude::*;
+use bevy_composable::app_impl::ComplexSpawnable;
+
+/// Volume lost per second
+pub const EVAPORATION_RATE: f32 = 0.25;
+/// How much faster puddles boil off while something burning stands in them
+pub const HEATED_EVAPORATION: f32 = 3.0;
+/// How far apart horizontally a droplet and a puddle can be and still count as the same puddle
+pub const MERGE_DISTANCE: f32 = 4.0;
+/// How close to a puddle a hit has to land to set it off
+pub const PUDDLE_REACH: f32 = 6.0;
+/// Damage dealt each tick to anything standing in an electrified puddle
+pub const ELECTRIFIED_PUDDLE_DAMAGE: isize = 4;
+/// Friction multiplier for anything standing on a frozen puddle
+pub const ICE_FRICTION: f32 = 0.1;
+
+pub fn splat_droplets(
+    trigger: Trigger<OnCollisionStart>,
+    sensors: Query<(), With<Sensor>>,
+    droplets: Query<(&Transform, &Droplet)>,
+    mut puddles: Query<(
+        Entity,
+        &Transform,
+        &mut Puddle,
+        &mut PuddleVolume,
+        &mut Sprite,
+    )>,
+    sprites: Res<LiquidSprites>,
+    mut commands: Commands,
+) {
+    let droplet = trigger.target();
+    let other = trigger.collider;
+
+    // We don't want droplets to do things when they hit sensors
+    if sensors.get(other).is_ok() {
+        return;
+    }
+    let Ok((transform, Droplet(liquid))) = droplets.get(droplet) else {
+        return;
+    };
+    let loc = transform.translation;
+    commands.entity(droplet).despawn();
+
+    // Puddles fall straight down, so anything in the same column just below is where this lands
+    let landing_in = puddles
+        .iter_mut()
+        .filter(|(_, puddle_transform, ..)| {
+            let offset = loc.xy() - puddle_transform.translation.xy();
+            offset.x.abs() < MERGE_DISTANCE && (0. ..LEVEL_SCALING).contains(&offset.y)
+        })
+        .find(|(_, _, puddle, volume, _)| {
+            mix_liquids((puddle.0, volume.volume), (*liquid, DROPLET_VOLUME)) != LiquidMix::Separate
+        });
+
+    let Some((_, _, mut puddle_liquid, mut volume, mut sprite)) = landing_in else {
+        commands.compose(puddle(*liquid) + pos(loc.x, loc.y));
+        return;
+    };
+
+    match mix_liquids((puddle_liquid.0, volume.volume), (*liquid, DROPLET_VOLUME)) {
+        LiquidMix::Combined(result, new_volume) => {
+            volume.volume = new_volume;
+            set_puddle_liquid(&mut puddle_liquid, &mut sprite, result, &sprites);
+        }
+        LiquidMix::Layered { top, bottom } => {
+            volume.volume = top.1;
+            // More of what's already underneath pools with it rather than replacing it
+            volume.underlayer = Some(match volume.underlayer {
+                Some((liquid, existing)) if liquid == bottom.0 => {
+                    (liquid, (existing + bottom.1).min(MAX_PUDDLE_VOLUME))
+                }
+                _ => bottom,
+            });
+            set_puddle_liquid(&mut puddle_liquid, &mut sprite, top.0, &sprites);
+        }
+        LiquidMix::Separate => {}
+    }
+}
+
+pub fn evaporate_puddles(
+    time: Res<Time>,
+    collisions: Collisions,
+    mut puddles: Query<(
+        Entity,
+        &mut Puddle,
+        &mut PuddleVolume,
+        &PuddleCondition,
+        &mut Sprite,
+    )>,
+    burning_enemies: Query<
+        (),
+        (
+            With<EnemyHealth>,
+            Or<(With<StatusEffect<Burned>>, With<StatusEffect<Ignited>>)>,
+        ),
+    >,
+    sprites: Res<LiquidSprites>,
+    mut commands: Commands,
+) {
+    for (entity, mut puddle, mut volume, condition, mut sprite) in puddles.iter_mut() {
+        if condition.state == PuddleState::Frozen {
+            continue;
+        }
+        let heated = condition.state == PuddleState::Burning
+            || collisions
+                .entities_colliding_with(entity)
+                .any(|other| burning_enemies.contains(other));
+        let rate = if heated {
+            EVAPORATION_RATE * HEATED_EVAPORATION
+        } else {
+            EVAPORATION_RATE
+        };
+        volume.volume -= rate * time.delta_secs();
+
+        if volume.volume > 0. {
+            continue;
+        }
+        match volume.underlayer.take() {
+            Some((liquid, remaining)) => {
+                volume.volume = remaining;
+                set_puddle_liquid(&mut puddle, &mut sprite, liquid, &sprites);
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+}
+
+/// Lightning, fire and ice landing near a puddle can turn it into a hazard
+pub fn set_off_puddles(
+    mut hits: EventReader<TryDamageToEnemy>,
+    enemies: Query<&GlobalTransform, With<EnemyHealth>>,
+    mut puddles: Query<(&GlobalTransform, &Puddle, &mut PuddleCondition)>,
+) {
+    for hit in hits.read() {
+        // Puddles shocking their own occupants shouldn't keep themselves going
+        if hit.source.is_some_and(|source| puddles.contains(source)) {
+            continue;
+        }
+        let Ok(enemy_transform) = enemies.get(hit.enemy) else {
+            continue;
+        };
+        let loc = enemy_transform.translation().xy();
+        for (transform, Puddle(liquid), mut condition) in puddles.iter_mut() {
+            if transform.translation().xy().distance(loc) > PUDDLE_REACH {
+                continue;
+            }
+            match puddle_reaction(*liquid, condition.state, hit.damage_type) {
+                Some(state) if state != condition.state => condition.set(state),
+                _ => {}
+            }
+        }
+    }
+}
+
+pub fn tick_puddle_hazards(
+    time: Res<Time>,
+    collisions: Collisions,
+    mut puddles: Query<(Entity, &mut PuddleCondition)>,
+    enemies: Query<(), With<EnemyHealth>>,
+    mut damage: EventWriter<TryDamageToEnemy>,
+    mut statuses: EventWriter<TryApplyStatus>,
+) {
+    for (puddle, mut condition) in puddles.iter_mut() {
+        if condition.state == PuddleState::Calm {
+            continue;
+        }
+        condition.duration.tick(time.delta());
+        if condition.duration.finished() {
+            condition.set(PuddleState::Calm);
+            continue;
+        }
+        condition.tick.tick(time.delta());
+        if !condition.tick.just_finished() {
+            continue;
+        }
+
+        for enemy in collisions
+            .entities_colliding_with(puddle)
+            .filter(|e| enemies.contains(*e))
+        {
+            match condition.state {
+                PuddleState::Electrified => {
+                    damage.write(TryDamageToEnemy {
+                        damage: ELECTRIFIED_PUDDLE_DAMAGE,
+                        strength: 1,
+                        damage_type: DamageType::Lightning,
+                        enemy,
+                        source: Some(puddle),
+                    });
+                }
+                PuddleState::Burning => {
+                    statuses.write(TryApplyStatus {
+                        status: StatusEnum::Ignited,
+                        enemy,
+                        strength: 1,
+                    });
+                }
+                PuddleState::Frozen | PuddleState::Calm => {}
+            }
+        }
+    }
+}
+
+pub fn slip_on_frozen_puddles(
+    collisions: Collisions,
+    puddles: Query<(Entity, &PuddleCondition)>,
+    mut enemies: Query<&mut Stat<StatFriction>, With<EnemyHealth>>,
+) {
+    for (puddle, condition) in puddles.iter() {
+        if condition.state != PuddleState::Frozen {
+            continue;
+        }
+        for other in collisions.entities_colliding_with(puddle) {
+            if let Ok(mut friction) = enemies.get_mut(other) {
+                friction.multiplier(ICE_FRICTION);
+            }
+        }
+    }
+}
+
+pub fn tint_puddles(mut puddles: Query<(&PuddleCondition, &mut Sprite), Changed<PuddleCondition>>) {
+    for (condition, mut sprite) in puddles.iter_mut() {
+        sprite.color = condition.state.tint();
+    }
+}
+
+pub fn scale_puddles_by_volume(
+    mut puddles: Query<(&PuddleVolume, &mut Transform), Changed<PuddleVolume>>,
+) {
+    for (volume, mut transform) in puddles.iter_mut() {
+        let total = volume.volume + volume.underlayer.map(|(_, v)| v).unwrap_or(0.);
+        transform.scale.x = (0.5 + total / DROPLET_VOLUME * 0.5).clamp(0.5, 2.5);
+    }
+}
+
+fn set_puddle_liquid(
+    puddle: &mut Puddle,
+    sprite: &mut Sprite,
+    liquid: LiquidType,
+    sprites: &LiquidSprites,
+) {
+    if puddle.0 == liquid {
+        return;
+    }
+    puddle.0 = liquid;
+    let (image, layout) = sprites.puddle(&liquid);
+    sprite.image = image.clone();
+    if let Some(atlas) = sprite.texture_atlas.as_mut() {
+        atlas.layout = layout.clone();
+    }
+}
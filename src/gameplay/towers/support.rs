@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+use crate::{
+    data::{
+        AuraEffect, Tower,
+        stats::{ExtraShots, Stat, StatusStrength, TowerCooldown},
+    },
+    level::components::Adjacent,
+};
+
+/// The grid cell a tower sits in, taken from the wall panel it was placed on
+#[derive(Copy, Clone, Debug, Reflect, Component, PartialEq, Eq)]
+pub struct TowerCell(pub IVec2);
+
+pub fn assign_tower_cells(
+    towers: Query<(Entity, &ChildOf), Added<Tower>>,
+    panels: Query<&Adjacent>,
+    mut commands: Commands,
+) {
+    for (tower, parent) in towers.iter() {
+        if let Ok(adjacent) = panels.get(parent.0) {
+            commands.entity(tower).insert(TowerCell(adjacent.cell()));
+        }
+    }
+}
+
+/// Support towers re-apply their auras every frame, so buffs disappear as soon as the support
+/// tower does
+pub fn apply_auras(
+    supports: Query<(Entity, &Tower, &TowerCell)>,
+    mut towers: Query<(
+        Entity,
+        &Tower,
+        &TowerCell,
+        &mut Stat<TowerCooldown>,
+        &mut Stat<StatusStrength>,
+        &mut Stat<ExtraShots>,
+    )>,
+) {
+    for (support, support_tower, TowerCell(center)) in supports.iter() {
+        let Some(aura) = support_tower.aura() else {
+            continue;
+        };
+        for (e, tower, TowerCell(cell), mut cooldown, mut status_strength, mut extra_shots) in
+            towers.iter_mut()
+        {
+            let offset = (*cell - *center).abs();
+            if e == support || offset.max_element() > aura.radius || !aura.affects(tower) {
+                continue;
+            }
+            match aura.effect {
+                AuraEffect::CooldownMultiplier(multiplier) => cooldown.multiplier(multiplier),
+                AuraEffect::StatusStrengthBonus(bonus) => status_strength.postmul_bonus(bonus),
+                AuraEffect::ExtraShot(_) => extra_shots.postmul_bonus(1.),
+            }
+        }
+    }
+}
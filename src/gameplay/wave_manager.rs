@@ -12,18 +12,15 @@ use bevy::{
     time::{Time, Timer},
     transform::components::Transform,
 };
-use bevy_composable::{
-    app_impl::{ComplexSpawnable, ComponentTreeable},
-    tree::ComponentTree,
-};
+use bevy_composable::app_impl::{ComplexSpawnable, ComponentTreeable};
 use std::{collections::VecDeque, time::Duration};
 
 use crate::{
     PausableSystems,
     assets::UiAssets,
-    data::levels::LevelData,
+    data::{enemies::EnemyArchetype, levels::LevelData},
     level::components::StartNode,
-    prefabs::enemies::{basic_trooper, chonkus_trooper, turbo_trooper},
+    prefabs::enemies::enemy,
     prelude::*,
     theme::widget,
 };
@@ -42,7 +39,7 @@ pub struct Wave(pub VecDeque<(Group, Duration)>);
 
 // Enemies don't spawn all at once in a wave, they spawn in delayed groups.
 #[derive(Clone, Component)]
-pub struct Group(pub Vec<ComponentTree>);
+pub struct Group(pub Vec<EnemyArchetype>);
 
 // Enemies don't spawn all at once in a wave, they spawn in delayed groups.
 #[derive(States, Default, Debug, Hash, PartialEq, Eq, Copy, Clone)]
@@ -109,8 +106,8 @@ pub fn spawn_next_wave(
     if wave_manager.wave_timer.finished() {
         if let (Some(wave), Ok(loc)) = (wave_manager.current_wave.as_mut(), start_loc.single()) {
             if let Some((group, duration)) = wave.0.pop_front() {
-                for enemy in group.0.iter() {
-                    commands.compose(enemy.clone() + loc.clone().store());
+                for archetype in group.0.iter() {
+                    commands.compose(enemy(*archetype) + loc.clone().store());
                 }
                 wave_manager.wave_timer.set_duration(duration);
                 wave_manager.wave_timer.reset();
@@ -136,16 +133,57 @@ impl WaveManager {
     pub fn remaining_waves(&self) -> usize {
         self.upcoming_waves.len()
     }
+
+    /// Waves that haven't started yet, in the order they'll be sent
+    pub fn upcoming_waves(&self) -> impl Iterator<Item = &Wave> {
+        self.upcoming_waves.iter()
+    }
+}
+
+impl Wave {
+    /// How many of each enemy the wave sends, in the order they first appear
+    pub fn composition(&self) -> Vec<(EnemyArchetype, usize)> {
+        let mut counts: Vec<(EnemyArchetype, usize)> = vec![];
+        for archetype in self.0.iter().flat_map(|(group, _)| group.0.iter()) {
+            match counts.iter_mut().find(|(counted, _)| counted == archetype) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((*archetype, 1)),
+            }
+        }
+        counts
+    }
+
+    pub fn total_health(&self) -> isize {
+        self.0
+            .iter()
+            .flat_map(|(group, _)| group.0.iter())
+            .map(|archetype| archetype.max_health())
+            .sum()
+    }
+
+    /// Each group along with how many seconds into the wave it spawns. A group's delay is the wait
+    /// before the group after it.
+    pub fn timeline(&self) -> Vec<(f32, &Group)> {
+        let mut elapsed = 0.;
+        self.0
+            .iter()
+            .map(|(group, delay)| {
+                let spawns_at = elapsed;
+                elapsed += delay.as_secs_f32();
+                (spawns_at, group)
+            })
+            .collect()
+    }
 }
 
-impl From<Vec<ComponentTree>> for Group {
-    fn from(value: Vec<ComponentTree>) -> Self {
+impl From<Vec<EnemyArchetype>> for Group {
+    fn from(value: Vec<EnemyArchetype>) -> Self {
         Self(value)
     }
 }
 
-impl From<Vec<(Vec<ComponentTree>, f32)>> for Wave {
-    fn from(value: Vec<(Vec<ComponentTree>, f32)>) -> Self {
+impl From<Vec<(Vec<EnemyArchetype>, f32)>> for Wave {
+    fn from(value: Vec<(Vec<EnemyArchetype>, f32)>) -> Self {
         Wave(
             value
                 .iter()
@@ -298,36 +336,36 @@ pub fn test_waves() -> VecDeque<Wave> {
         //
         // Wave 1
         vec![
-            (vec![basic_trooper()], 2.),
-            (vec![basic_trooper(), turbo_trooper()], 0.),
+            (vec![EnemyArchetype::Basic], 2.),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.),
         ]
         .into(),
         //
         // Wave 2
         vec![
-            (vec![chonkus_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.),
+            (vec![EnemyArchetype::Chonkus], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.),
         ]
         .into(),
         // Wave 3
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![basic_trooper(), turbo_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Basic, EnemyArchetype::Turbo], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
         ]
         .into(),
         // Wave 4
         vec![
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
-            (vec![chonkus_trooper(), basic_trooper()], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
+            (vec![EnemyArchetype::Chonkus, EnemyArchetype::Basic], 0.5),
         ]
         .into(),
     ]
@@ -0,0 +1,149 @@
+use crate::assets::GameAssets;
+use crate::gameplay::wave_manager::{Wave, WaveManager};
+use crate::prelude::*;
+use crate::theme::palette::LABEL_TEXT;
+use crate::theme::widget;
+use bevy::prelude::*;
+
+/// Timeline entries shown before the rest are summarised, so long waves don't fill the screen
+const MAX_TIMELINE_ROWS: usize = 6;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_wave_preview);
+    app.add_systems(
+        Update,
+        update_wave_preview.run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Holds the preview's contents, which are rebuilt whenever the next wave changes
+#[derive(Component)]
+struct WavePreview;
+
+fn spawn_wave_preview(mut commands: Commands) {
+    commands.spawn((
+        StateScoped(Screen::Gameplay),
+        Name::new("Wave Preview"),
+        WavePreview,
+        BackgroundColor(Color::BLACK.with_alpha(0.75)),
+        BorderRadius::all(Val::Px(8.0)),
+        Node {
+            position_type: PositionType::Absolute,
+            // Just below the "Next Wave" button
+            top: Val::Px(200.0),
+            left: Val::Px(12.0),
+            width: Val::Px(280.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            row_gap: Val::Px(4.0),
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        Pickable::IGNORE,
+    ));
+}
+
+/// Rebuilds the preview when a wave is sent. `WaveManager` changes every frame while its timer
+/// ticks, so this remembers which panel it last filled and how many waves were left then.
+fn update_wave_preview(
+    wave_manager: Res<WaveManager>,
+    preview: Query<Entity, With<WavePreview>>,
+    assets: Res<GameAssets>,
+    mut shown_for: Local<Option<(Entity, usize)>>,
+    mut commands: Commands,
+) {
+    let Ok(preview) = preview.single() else {
+        return;
+    };
+    let remaining = wave_manager.remaining_waves();
+    if *shown_for == Some((preview, remaining)) {
+        return;
+    }
+    *shown_for = Some((preview, remaining));
+
+    commands.entity(preview).despawn_related::<Children>();
+    commands.entity(preview).with_children(|parent| {
+        let Some(wave) = wave_manager.upcoming_waves().next() else {
+            parent.spawn(widget::label("No more waves"));
+            return;
+        };
+        parent.spawn(widget::label(format!("Next wave ({remaining} remain)")));
+        spawn_composition(parent, wave, &assets);
+        parent.spawn(widget::body_text(format!(
+            "Total health: {}",
+            wave.total_health()
+        )));
+        spawn_timeline(parent, wave);
+    });
+}
+
+fn spawn_composition(parent: &mut ChildSpawnerCommands, wave: &Wave, assets: &GameAssets) {
+    for (archetype, count) in wave.composition() {
+        parent
+            .spawn(Node {
+                column_gap: Val::Px(8.0),
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Row,
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Node {
+                        width: Val::Px(32.0),
+                        height: Val::Px(32.0),
+                        flex_shrink: 0.0,
+                        ..default()
+                    },
+                    ImageNode {
+                        image: assets.troopers(),
+                        texture_atlas: Some(TextureAtlas {
+                            layout: assets.troopers_layout(),
+                            index: archetype.icon_frame(),
+                        }),
+                        color: archetype.tint(),
+                        ..default()
+                    },
+                ));
+                row.spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                })
+                .with_children(|text| {
+                    text.spawn(widget::body_text(format!("{count}x {}", archetype.name())));
+                    if !archetype.traits().is_empty() {
+                        text.spawn((
+                            Text::new(archetype.traits().join(", ")),
+                            TextFont::from_font_size(12.0),
+                            TextColor(LABEL_TEXT),
+                        ));
+                    }
+                });
+            });
+    }
+}
+
+fn spawn_timeline(parent: &mut ChildSpawnerCommands, wave: &Wave) {
+    let timeline = wave.timeline();
+    parent.spawn(widget::label("Timeline"));
+    for (spawns_at, group) in timeline.iter().take(MAX_TIMELINE_ROWS) {
+        let names = group
+            .0
+            .iter()
+            .map(|archetype| archetype.name())
+            .collect::<Vec<_>>()
+            .join(", ");
+        parent.spawn((
+            Text::new(format!("{spawns_at:>5.1}s  {names}")),
+            TextFont::from_font_size(12.0),
+        ));
+    }
+    if timeline.len() > MAX_TIMELINE_ROWS {
+        let (last_at, _) = timeline[timeline.len() - 1];
+        parent.spawn((
+            Text::new(format!(
+                "...{} more groups until {last_at:.1}s",
+                timeline.len() - MAX_TIMELINE_ROWS
+            )),
+            TextFont::from_font_size(12.0),
+        ));
+    }
+}
@@ -89,6 +89,29 @@ impl Adjacent {
             exact_position: exact,
         }
     }
+
+    /// Which side of its cell this surface is on, which is also the way a tower placed here is
+    /// mounted
+    pub fn face(&self) -> CellDirection {
+        match self.exact_position {
+            ExactPosition::Floor => CellDirection::Down,
+            ExactPosition::Ceiling => CellDirection::Up,
+            ExactPosition::Wall(WallDirection::Left) => CellDirection::Left,
+            ExactPosition::Wall(WallDirection::Right) => CellDirection::Right,
+        }
+    }
+
+    /// The grid cell a tower placed on this surface sits in. Walls and floors are shared between
+    /// two cells, and which one is meant depends on the side the tower is on.
+    pub fn cell(&self) -> IVec2 {
+        let (x, y) = (self.id.unit_x as i32, self.id.unit_y as i32);
+        match self.exact_position {
+            ExactPosition::Floor => IVec2::new(x, y),
+            ExactPosition::Ceiling => IVec2::new(x, y - 1),
+            ExactPosition::Wall(WallDirection::Left) => IVec2::new(x, y),
+            ExactPosition::Wall(WallDirection::Right) => IVec2::new(x - 1, y),
+        }
+    }
 }
 
 impl LevelParent {
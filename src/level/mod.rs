@@ -1,3 +1,4 @@
+use crate::data::levels::LevelData;
 use crate::level::resource::*;
 use bevy::prelude::*;
 use components::{Architecture, Ceiling, Floor, LevelParent, PathNode, Wall, WallDirection};
@@ -12,7 +13,10 @@ pub fn plugin(app: &mut App) {
     app.insert_resource(Level::default())
         .insert_resource(CurrentLoadedLevel(START_LEVEL))
         .insert_resource(LevelSelect(START_LEVEL))
-        .insert_resource(UnlockedLevels(vec![START_LEVEL]));
+        .insert_resource(UnlockedLevels(vec![START_LEVEL]))
+        .insert_resource(UnlockedTowers(
+            LevelData::default().new_towers(START_LEVEL).to_vec(),
+        ));
 
     app.register_type::<Level>()
         .register_type::<WallDirection>()
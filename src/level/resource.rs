@@ -10,6 +10,24 @@ pub struct LevelSelect(pub usize);
 #[derive(Resource, Clone, Debug, Hash, Reflect, PartialEq, Eq)]
 pub struct UnlockedLevels(pub Vec<usize>);
 
+/// Towers the player has unlocked so far in the campaign
+#[derive(Resource, Clone, Debug, Hash, Reflect, PartialEq, Eq)]
+pub struct UnlockedTowers(pub Vec<Tower>);
+
+impl UnlockedTowers {
+    pub fn unlock(&mut self, towers: &[Tower]) {
+        for tower in towers {
+            if !self.0.contains(tower) {
+                self.0.push(*tower);
+            }
+        }
+    }
+
+    pub fn contains(&self, tower: Tower) -> bool {
+        self.0.contains(&tower)
+    }
+}
+
 #[derive(Event, Debug, Hash, PartialEq, Eq, Clone, Reflect)]
 pub struct GotoNextLevel(pub usize);
 
@@ -122,6 +140,61 @@ impl Level {
         }
     }
 
+    /// Whether there's a floor along the bottom of cell (x, y). Outside the level counts as solid.
+    pub fn has_floor(&self, x: i32, y: i32) -> bool {
+        let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) else {
+            return true;
+        };
+        self.floors
+            .get(x)
+            .and_then(|column| column.get(y))
+            .copied()
+            .unwrap_or(true)
+    }
+
+    /// Whether there's a wall along the left side of cell (x, y). Outside the level counts as
+    /// solid.
+    pub fn has_wall(&self, x: i32, y: i32) -> bool {
+        let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) else {
+            return true;
+        };
+        self.walls
+            .get(x)
+            .and_then(|column| column.get(y))
+            .copied()
+            .unwrap_or(true)
+    }
+
+    /// Whether moving out of `cell` in `direction` would go through a wall or floor
+    pub fn blocked(&self, cell: IVec2, direction: CellDirection) -> bool {
+        match direction {
+            CellDirection::Up => self.has_floor(cell.x, cell.y + 1),
+            CellDirection::Down => self.has_floor(cell.x, cell.y),
+            CellDirection::Left => self.has_wall(cell.x, cell.y),
+            CellDirection::Right => self.has_wall(cell.x + 1, cell.y),
+        }
+    }
+
+    /// The faces of `cell` that have a wall or floor to mount a tower on
+    pub fn surfaces(&self, cell: IVec2) -> Vec<CellDirection> {
+        if !self.contains(cell) {
+            return vec![];
+        }
+        [
+            CellDirection::Down,
+            CellDirection::Left,
+            CellDirection::Up,
+            CellDirection::Right,
+        ]
+        .into_iter()
+        .filter(|face| self.blocked(cell, *face))
+        .collect()
+    }
+
+    pub fn contains(&self, cell: IVec2) -> bool {
+        (0..self.width as i32).contains(&cell.x) && (0..self.height as i32).contains(&cell.y)
+    }
+
     // Takes a string representing a rectangular grid of <v>^ characters
     pub fn from_str<I: Into<String>>(map_str: I) -> Self {
         let map_str: String = map_str.into();
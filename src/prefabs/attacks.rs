@@ -1,11 +1,17 @@
 use super::physics::GamePhysicsLayer as GPL;
 use super::utils::{DropletSprite, PuddleSprite};
-use crate::data::projectiles::{Droplet, LiquidType, Puddle};
-use crate::gameplay::shared_systems::Lifetime;
+use crate::{
+    data::projectiles::{
+        DROPLET_VOLUME, Droplet, LiquidType, Projectile, ProjectileHitbox, ProjectileSpec, Puddle,
+        PuddleCondition, PuddleVolume,
+    },
+    gameplay::shared_systems::Lifetime,
+};
 use avian2d::prelude::{
-    Collider, CollisionEventsEnabled, CollisionLayers, LinearVelocity, Mass, RigidBody, Sensor,
+    Collider, CollisionEventsEnabled, CollisionLayers, GravityScale, LinearVelocity, Mass,
+    Restitution, RigidBody, Sensor,
 };
-use bevy::prelude::*;
+use bevy::{color::palettes::css, prelude::*};
 use bevy_composable::{app_impl::ComponentTreeable, tree::ComponentTree, wrappers::name};
 
 pub fn droplet(liquid: LiquidType) -> ComponentTree {
@@ -28,14 +34,44 @@ pub fn puddle(liquid: LiquidType) -> ComponentTree {
         PuddleSprite(liquid),
         Visibility::Visible,
         Puddle(liquid),
+        PuddleVolume::new(DROPLET_VOLUME),
+        PuddleCondition::default(),
         Collider::ellipse(3.5, 0.75),
         CollisionLayers::new(GPL::Projectiles, [GPL::Enemy, GPL::Level]),
         Sensor,
         RigidBody::Kinematic,
         LinearVelocity(Vec2::new(0., -20.)),
         CollisionEventsEnabled,
-        Lifetime::new(4.0),
     )
         .store()
         + name("Puddle")
 }
+
+/// Projectiles that never land anywhere are cleaned up after this long
+pub const PROJECTILE_LIFETIME: f32 = 8.;
+
+pub fn projectile(spec: &ProjectileSpec, damage: f32, status_strength: f32) -> ComponentTree {
+    (
+        Sprite::from_color(css::DARK_SLATE_GRAY, Vec2::splat(2.5)),
+        Visibility::Visible,
+        Projectile::new(spec, damage, status_strength),
+        Collider::circle(1.),
+        CollisionLayers::new(GPL::Projectiles, [GPL::Level]),
+        RigidBody::Dynamic,
+        GravityScale(spec.gravity),
+        Restitution::new(0.7),
+        Mass(0.1),
+        CollisionEventsEnabled,
+        Lifetime::new(PROJECTILE_LIFETIME),
+    )
+        .store()
+        + name("Projectile")
+        << (
+            ProjectileHitbox,
+            Collider::circle(1.5),
+            Sensor,
+            CollisionLayers::new(GPL::Projectiles, [GPL::Enemy]),
+            CollisionEventsEnabled,
+        )
+            .store()
+}
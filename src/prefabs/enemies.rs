@@ -1,19 +1,32 @@
 use super::{
     physics::GamePhysicsLayer as GPL,
-    utils::{color, image, layout, mesh},
+    utils::{color, image, layout, mesh, tint},
 };
 use crate::{
     assets::GameAssets,
-    data::stats::{DamageMultiplier, MoveSpeed, Stat, StatFriction},
+    data::{
+        enemies::EnemyArchetype,
+        stats::{Armor, DamageMultiplier, MoveSpeed, Stat, StatFriction},
+        status_effects::{StatusEnum, StatusResistances},
+    },
     demo::{
-        enemy_health::{EnemyHealth, EnemyHealthBar},
+        enemy_health::{
+            DeathEffect, EnemyHealth, EnemyHealthBar, Invulnerability, LivesPenalty, Shield,
+        },
         enemy_movement::MovementDirection,
     },
-    gameplay::animation::AnimationFrameQueue,
+    gameplay::{
+        animation::AnimationFrameQueue,
+        boss::{Boss, BossPhase},
+        status_effects::common::ActiveStatuses,
+    },
     prelude::*,
 };
 use crate::{
-    data::{projectiles::DamageType, stats::DamageMultiplierAll},
+    data::{
+        projectiles::{AttackSpecification, DamageType},
+        stats::DamageMultiplierAll,
+    },
     demo::enemy_health::Bounty,
 };
 use avian2d::prelude::{
@@ -26,9 +39,19 @@ use std::default::Default;
 #[derive(Component, Reflect, Debug, PartialEq, Eq, Clone, Copy)]
 pub struct EnemySprite;
 
+pub fn enemy(archetype: EnemyArchetype) -> ComponentTree {
+    match archetype {
+        EnemyArchetype::Basic => basic_trooper(),
+        EnemyArchetype::Chonkus => chonkus_trooper(),
+        EnemyArchetype::Turbo => turbo_trooper(),
+        EnemyArchetype::Ember => ember_trooper(),
+        EnemyArchetype::Warlord => warlord_trooper(),
+    }
+}
+
 pub fn basic_trooper() -> ComponentTree {
     let animation = AnimationFrameQueue::new(&[8, 9, 10, 11, 12, 13, 14]);
-    name("Minor Trooper") + enemy_requirements(Vec2::new(3., 4.), 30., 10)
+    enemy_requirements(EnemyArchetype::Basic, Vec2::new(3., 4.), 30., 10)
         << ((
             Transform::from_translation(Vec3::new(0., 0.5, 0.)),
             Pickable::default(),
@@ -43,9 +66,13 @@ pub fn basic_trooper() -> ComponentTree {
 
 pub fn chonkus_trooper() -> ComponentTree {
     let animation = AnimationFrameQueue::new(&[16, 16, 16, 17, 17, 17, 18, 18, 18, 19, 19, 19]);
-    name("Major Trooper")
-        + enemy_requirements(Vec2::new(4., 5.0), 20., 20)
-        + Stat::<DamageMultiplierAll>::new(0.75).store()
+    enemy_requirements(EnemyArchetype::Chonkus, Vec2::new(4., 5.0), 20., 20)
+        + (
+            Stat::<DamageMultiplierAll>::new(0.75),
+            Stat::<Armor>::new(2.0),
+            StatusResistances::default().resist(&[StatusEnum::Chilled, StatusEnum::Frozen], 1, 0.5),
+        )
+            .store()
         << ((
             Transform::from_translation(Vec3::new(0., 1., 0.)),
             Pickable::default(),
@@ -60,8 +87,7 @@ pub fn chonkus_trooper() -> ComponentTree {
 
 pub fn turbo_trooper() -> ComponentTree {
     let animation = AnimationFrameQueue::new(&[0, 1, 2, 3, 4, 5, 6, 7]);
-    name("Turbo Trooper")
-        + enemy_requirements(Vec2::new(2., 3.), 45., 15)
+    enemy_requirements(EnemyArchetype::Turbo, Vec2::new(2., 3.), 45., 15)
         + Stat::<DamageMultiplierAll>::new(1.15).store()
         << ((
             // Transform::from_scale(Vec3::splat(0.10)),
@@ -75,25 +101,100 @@ pub fn turbo_trooper() -> ComponentTree {
             << health_bar(2.5))
 }
 
-pub fn enemy_requirements(size: Vec2, speed: f32, bounty: i32) -> ComponentTree {
-    (
-        StateScoped(Screen::Gameplay),
-        Bounty(bounty),
-        EnemyHealth::new(100),
-        MovementDirection::default(),
-        RigidBody::Dynamic,
-        Friction::new(0.3),
-        Visibility::Hidden,
-        ShowDelay::new(),
-        LinearDamping(1.5),
-        GravityScale(1.0),
-        Mass(5.),
-        LockedAxes::ROTATION_LOCKED,
-        Collider::round_rectangle(size.x, size.y, 0.5),
-        CollisionLayers::new(GPL::Enemy, [GPL::Default, GPL::Level, GPL::Projectiles]),
-    )
-        .store()
+pub fn ember_trooper() -> ComponentTree {
+    let animation = AnimationFrameQueue::new(&[8, 9, 10, 11, 12, 13, 14]);
+    enemy_requirements(EnemyArchetype::Ember, Vec2::new(3., 4.), 30., 15)
+        + (
+            StatusResistances::default()
+                .immune(&[StatusEnum::Burned, StatusEnum::Ignited])
+                .resist(&[StatusEnum::Wet], 0, 0.5),
+            Stat::<DamageMultiplier<{ DamageType::Burning }>>::new(0.25),
+            Stat::<DamageMultiplier<{ DamageType::Cold }>>::new(1.5),
+            // Goes up in flames when it dies
+            DeathEffect(vec![AttackSpecification::Explosion {
+                radius: 10.,
+                falloff: 0.5,
+                payload: vec![
+                    AttackSpecification::Damage(DamageType::Burning, 15),
+                    AttackSpecification::Status(StatusEnum::Burned),
+                ],
+            }]),
+        )
+            .store()
+        << ((
+            Transform::from_translation(Vec3::new(0., 0.5, 0.)),
+            Pickable::default(),
+            EnemySprite,
+        )
+            .store()
+            + animation.store()
+            + image(GameAssets::troopers, 6.0)
+            + tint(EnemyArchetype::Ember.tint())
+            + layout(GameAssets::troopers_layout)
+            << health_bar(3.))
+}
+
+pub fn warlord_trooper() -> ComponentTree {
+    let animation = AnimationFrameQueue::new(&[16, 16, 16, 17, 17, 17, 18, 18, 18, 19, 19, 19]);
+    enemy_requirements(EnemyArchetype::Warlord, Vec2::new(6., 8.), 14., 250)
+        + (
+            LivesPenalty(5),
+            Shield::new(250, 50., 3.),
+            Stat::<Armor>::new(3.0),
+            Boss::new(vec![
+                BossPhase::new(1.0, 1.0, &[]),
+                BossPhase::new(0.5, 1.4, &[StatusEnum::Chilled, StatusEnum::Frozen]),
+                BossPhase::new(
+                    0.2,
+                    1.8,
+                    &[StatusEnum::Chilled, StatusEnum::Frozen, StatusEnum::Oiled],
+                ),
+            ]),
+            Stat::<DamageMultiplierAll>::new(0.9),
+        )
+            .store()
+        << ((
+            Transform::from_translation(Vec3::new(0., 1.5, 0.)),
+            Pickable::default(),
+            EnemySprite,
+        )
+            .store()
+            + animation.store()
+            + image(GameAssets::troopers, 12.0)
+            + layout(GameAssets::troopers_layout)
+            << health_bar(6.5))
+}
+
+/// Components every enemy needs. The name and health come from the archetype, so the wave
+/// preview shows the same numbers the enemy spawns with.
+pub fn enemy_requirements(
+    archetype: EnemyArchetype,
+    size: Vec2,
+    speed: f32,
+    bounty: i32,
+) -> ComponentTree {
+    name(archetype.name())
+        + (
+            StateScoped(Screen::Gameplay),
+            Bounty(bounty),
+            EnemyHealth::new(archetype.max_health()),
+            Invulnerability::default(),
+            MovementDirection::default(),
+            RigidBody::Dynamic,
+            Friction::new(0.3),
+            Visibility::Hidden,
+            ShowDelay::new(),
+            LinearDamping(1.5),
+            GravityScale(1.0),
+            Mass(5.),
+            LockedAxes::ROTATION_LOCKED,
+            Collider::round_rectangle(size.x, size.y, 0.5),
+            CollisionLayers::new(GPL::Enemy, [GPL::Default, GPL::Level, GPL::Projectiles]),
+        )
+            .store()
         + (
+            archetype,
+            ActiveStatuses::default(),
             Stat::<MoveSpeed>::new(speed),
             Stat::<StatFriction>::new(0.3),
             Stat::<DamageMultiplierAll>::new(1.0),
@@ -3,7 +3,10 @@ use bevy::math::Vec2;
 use bevy_composable::{app_impl::ComponentTreeable, tree::ComponentTree, wrappers::name};
 
 use crate::{
-    data::Tower,
+    data::{
+        Tower, TowerArea,
+        stats::{ExtraShots, Stat, StatusStrength, TowerCooldown, TowerDamage, TriggerRange},
+    },
     gameplay::towers::{
         common::{TowerTriggerNeedsGravity, TowerTriggerRange},
         directional::FireDirection,
@@ -16,26 +19,31 @@ use super::{physics::GamePhysicsLayer as GPL, utils::TowerSprite};
 
 pub fn tower(tower: Tower, direction: CellDirection) -> ComponentTree {
     let tower_specific_components = match tower {
-        Tower::Piston => FireDirection(direction.flip()).store(),
+        Tower::Piston | Tower::Mortar => FireDirection(direction.flip()).store(),
         Tower::Fan => FanNeedsDirection.store(),
         Tower::Portal => todo!(),
         _ => ().store(),
     };
     (tower, direction, TowerSprite(tower, direction)).store()
         + name(tower.name())
+        + (
+            Stat::<TowerCooldown>::new(tower.cooldown()),
+            Stat::<TowerDamage>::new(1.),
+            Stat::<TriggerRange>::new(1.),
+            Stat::<StatusStrength>::new(1.),
+            Stat::<ExtraShots>::new(0.),
+        )
+            .store()
         + tower_specific_components
         + {
-            if tower.has_trigger_zone() {
-                if tower.gravity_influences_trigger() {
+            match tower.area() {
+                // Dropped down to the floor once the tower has a position to raycast from
+                TowerArea::ColumnBelow => {
                     ().store()
                         << (trigger_zone(Vec2::new(9., 9.5)) + TowerTriggerNeedsGravity.store())
-                } else if let Some(custom_trigger_zone) = tower.custom_trigger_zone() {
-                    ().store() << (trigger_zone(custom_trigger_zone))
-                } else {
-                    ().store() << trigger_zone(Vec2::new(9., 9.))
                 }
-            } else {
-                ().store()
+                TowerArea::Box(size) => ().store() << trigger_zone(size),
+                TowerArea::LineToWall | TowerArea::None => ().store(),
             }
         }
 }
@@ -4,6 +4,7 @@ use crate::{
     data::{projectiles::LiquidType, Tower},
     level::resource::CellDirection,
 };
+use bevy::color::Color;
 use bevy::math::Vec2;
 use bevy::{
     app::{Plugin, Update},
@@ -35,6 +36,10 @@ pub struct GiveMeMesh(pub Arc<dyn GimmieFn<Mesh, GameAssets>>);
 #[derive(Component, Clone)]
 pub struct GiveMeColor(pub Arc<dyn GimmieFn<ColorMaterial, GameAssets>>);
 
+/// Colors the sprite handed out by `GiveMeImage`
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+pub struct SpriteTint(pub Color);
+
 #[derive(Component, Clone, Debug, Reflect)]
 pub struct TowerSprite(pub Tower, pub CellDirection);
 
@@ -48,6 +53,10 @@ pub fn image(image: impl GimmieFn<Image, GameAssets>, scale: f32) -> ComponentTr
     GiveMeImage(Arc::new(image), Some(scale)).store()
 }
 
+pub fn tint(color: Color) -> ComponentTree {
+    SpriteTint(color).store()
+}
+
 pub fn layout(layout: impl GimmieFn<TextureAtlasLayout, GameAssets>) -> ComponentTree {
     GiveMeLayout(Arc::new(layout)).store()
 }
@@ -83,17 +92,18 @@ pub fn show_delay(mut query: Query<(&mut Visibility, &mut ShowDelay)>, time: Res
 pub fn give_images(
     trigger: Trigger<OnAdd, GiveMeImage>,
     images: Res<GameAssets>,
-    requests: Query<&GiveMeImage>,
+    requests: Query<(&GiveMeImage, Option<&SpriteTint>)>,
     mut commands: Commands,
 ) {
     let entity = trigger.target();
-    let request = requests.get(entity).unwrap();
+    let (request, tint) = requests.get(entity).unwrap();
     commands
         .get_entity(entity)
         .unwrap()
         .insert(Sprite {
             custom_size: request.1.map(|s| Vec2::splat(s)),
             image: request.0(&images),
+            color: tint.map(|t| t.0).unwrap_or(Color::WHITE),
             ..Default::default()
         })
         .remove::<GiveMeImage>();
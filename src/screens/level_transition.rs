@@ -1,10 +1,10 @@
 //! A splash screen that plays briefly at startup.
 
 use crate::assets::UiAssets;
-use crate::data::levels::LevelData;
+use crate::data::{Tower, levels::LevelData};
 use crate::level::resource::{CurrentLoadedLevel, LevelSelect};
 use crate::{screens::Screen, theme::prelude::*, AppSystems};
-use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy::{ecs::spawn::*, input::common_conditions::input_just_pressed, prelude::*};
 
 pub(super) fn plugin(app: &mut App) {
     // Spawn splash screen.
@@ -57,9 +57,16 @@ fn spawn_screen(
     assets: Res<UiAssets>,
     mut current_loaded_level: ResMut<CurrentLoadedLevel>,
     level_select: Res<LevelSelect>,
+    level_data: Res<LevelData>,
 ) {
     current_loaded_level.0 = level_select.0;
 
+    // The first level's towers are the starting set, so there's nothing new to introduce
+    let new_towers = level_data.new_towers(level_select.0);
+    if level_select.0 != 0 && !new_towers.is_empty() {
+        commands.spawn(new_tower_card(new_towers, &assets));
+    }
+
     if level_select.0 == 0 {
         commands.spawn((
             widget::ui_root("Controls"),
@@ -107,6 +114,93 @@ fn spawn_screen(
     }
 }
 
+/// Introduces the towers unlocked for this level. The screen waits for a click while it's shown.
+fn new_tower_card(towers: &[Tower], assets: &UiAssets) -> impl Bundle {
+    let rows: Vec<_> = towers
+        .iter()
+        .map(|tower| {
+            let icon = assets
+                .hotbar_icons
+                .get(tower.ui_asset_key())
+                .unwrap()
+                .clone();
+            (*tower, icon)
+        })
+        .collect();
+
+    (
+        Name::new("New Tower Card"),
+        GlobalZIndex(3),
+        StateScoped(Screen::LevelTransition),
+        BackgroundColor(Color::BLACK.with_alpha(0.85)),
+        BorderColor(ui_palette::LABEL_TEXT),
+        BorderRadius::all(Val::Px(8.0)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(32.0),
+            left: Val::Percent(50.0),
+            width: Val::Px(480.0),
+            margin: UiRect::left(Val::Px(-240.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            padding: UiRect::all(Val::Px(12.0)),
+            row_gap: Val::Px(8.0),
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        Children::spawn((
+            Spawn(widget::header(if towers.len() == 1 {
+                "New tower unlocked!"
+            } else {
+                "New towers unlocked!"
+            })),
+            SpawnIter(
+                rows.into_iter()
+                    .map(|(tower, icon)| new_tower_row(tower, icon)),
+            ),
+            Spawn(widget::body_text("Click to Continue")),
+        )),
+    )
+}
+
+fn new_tower_row(tower: Tower, icon: Handle<Image>) -> impl Bundle {
+    (
+        Name::new(tower.name()),
+        Node {
+            column_gap: Val::Px(12.0),
+            align_items: AlignItems::Center,
+            flex_direction: FlexDirection::Row,
+            ..default()
+        },
+        children![
+            (
+                Node {
+                    width: Val::Px(48.0),
+                    height: Val::Px(48.0),
+                    flex_shrink: 0.0,
+                    ..default()
+                },
+                ImageNode::new(icon).with_color(tower.sprite_tint().unwrap_or(Color::WHITE)),
+            ),
+            (
+                Node {
+                    row_gap: Val::Px(4.0),
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                children![
+                    widget::label(tower.name()),
+                    widget::body_text(tower.description()),
+                ],
+            )
+        ],
+    )
+}
+
+/// The controls screen and new tower introductions stay up until the player clicks
+fn waits_for_click(level: usize, level_data: &LevelData) -> bool {
+    level == 0 || !level_data.new_towers(level).is_empty()
+}
+
 #[derive(Component, Reflect)]
 #[reflect(Component)]
 struct ImageNodeFadeInOut {
@@ -163,8 +257,9 @@ fn tick_screen_timer(
     time: Res<Time>,
     mut timer: ResMut<ScreenTimer>,
     level_select: Res<LevelSelect>,
+    level_data: Res<LevelData>,
 ) {
-    if level_select.0 == 0 {
+    if waits_for_click(level_select.0, &level_data) {
         return;
     }
     timer.0.tick(time.delta());